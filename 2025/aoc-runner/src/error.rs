@@ -0,0 +1,225 @@
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+
+/// Where a parse error happened, line and column start at 1.
+#[derive(Debug, Eq, PartialEq, Clone, Copy)]
+pub struct Location {
+    pub day: u8,
+    pub line: usize,
+    pub column: usize,
+}
+
+#[derive(Debug, Eq, PartialEq, Clone)]
+pub enum ParseError {
+    InvalidNumber {
+        location: Location,
+        text: String,
+    },
+    Unexpected {
+        location: Location,
+        text: String,
+        expected: &'static str,
+    },
+    Missing {
+        location: Location,
+        text: String,
+        expected: &'static str,
+    },
+}
+
+impl Default for Location {
+    fn default() -> Self {
+        Location {
+            day: 0,
+            line: 1,
+            column: 1,
+        }
+    }
+}
+
+impl Display for Location {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "day {} line {} column {}",
+            self.day, self.line, self.column
+        )
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ParseError::InvalidNumber { location, text } => {
+                write!(f, "[{location}] Invalid number [{text}]")
+            }
+            ParseError::Unexpected {
+                location,
+                text,
+                expected,
+            } => write!(f, "[{location}] Expected {expected} but found [{text}]"),
+            ParseError::Missing {
+                location,
+                text,
+                expected,
+            } => write!(f, "[{location}] Missing {expected} in [{text}]"),
+        }
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// Why a part has no answer although its input was parsed.
+#[derive(Debug, Eq, PartialEq, Clone)]
+pub struct SolveError {
+    pub day: u8,
+    pub reason: String,
+}
+
+impl SolveError {
+    pub fn new(reason: impl Into<String>) -> Self {
+        SolveError {
+            day: 0,
+            reason: reason.into(),
+        }
+    }
+
+    pub fn on_day(mut self, day: u8) -> Self {
+        self.day = day;
+        self
+    }
+}
+
+impl Display for SolveError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "[day {}] {}", self.day, self.reason)
+    }
+}
+
+impl std::error::Error for SolveError {}
+
+fn at_column(column: usize) -> Location {
+    Location {
+        column,
+        ..Location::default()
+    }
+}
+
+impl ParseError {
+    pub fn invalid_number(column: usize, text: &str) -> Self {
+        ParseError::InvalidNumber {
+            location: at_column(column),
+            text: String::from(text),
+        }
+    }
+
+    pub fn unexpected(column: usize, text: &str, expected: &'static str) -> Self {
+        ParseError::Unexpected {
+            location: at_column(column),
+            text: String::from(text),
+            expected,
+        }
+    }
+
+    pub fn missing(column: usize, text: &str, expected: &'static str) -> Self {
+        ParseError::Missing {
+            location: at_column(column),
+            text: String::from(text),
+            expected,
+        }
+    }
+
+    pub fn location(&self) -> &Location {
+        match self {
+            ParseError::InvalidNumber { location, .. }
+            | ParseError::Unexpected { location, .. }
+            | ParseError::Missing { location, .. } => location,
+        }
+    }
+
+    fn location_mut(&mut self) -> &mut Location {
+        match self {
+            ParseError::InvalidNumber { location, .. }
+            | ParseError::Unexpected { location, .. }
+            | ParseError::Missing { location, .. } => location,
+        }
+    }
+
+    pub fn on_day(mut self, day: u8) -> Self {
+        self.location_mut().day = day;
+        self
+    }
+
+    /// Move an error found in a single line to its line in the whole input.
+    pub fn at_line(mut self, line: usize) -> Self {
+        self.location_mut().line = line;
+        self
+    }
+
+    /// Move an error found in a slice of a line by the offset of that slice.
+    pub fn shifted(mut self, offset: usize) -> Self {
+        self.location_mut().column += offset;
+        self
+    }
+}
+
+pub fn parse_number<T: FromStr>(text: &str, column: usize) -> Result<T, ParseError> {
+    text.parse::<T>()
+        .map_err(|_| ParseError::invalid_number(column, text))
+}
+
+/// Non-empty lines of the input with their line number.
+pub fn numbered_lines(input: &str) -> impl Iterator<Item = (usize, &str)> {
+    input
+        .lines()
+        .enumerate()
+        .filter(|(_, line)| !line.trim().is_empty())
+        .map(|(index, line)| (index + 1, line))
+}
+
+/// Column (starting at 1) of the first character left after trimming the start of the line.
+pub fn indentation(line: &str) -> usize {
+    line.len() - line.trim_start().len() + 1
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_location_is_moved() {
+        let error = ParseError::invalid_number(2, "4x")
+            .shifted(10)
+            .at_line(3)
+            .on_day(8);
+
+        assert_eq!(
+            error.location(),
+            &Location {
+                day: 8,
+                line: 3,
+                column: 12
+            }
+        );
+        assert_eq!(
+            error.to_string(),
+            "[day 8 line 3 column 12] Invalid number [4x]"
+        );
+    }
+
+    #[test]
+    fn test_parse_number() {
+        assert_eq!(parse_number::<i64>("42", 1), Ok(42));
+        assert_eq!(
+            parse_number::<i64>("4.2", 5),
+            Err(ParseError::invalid_number(5, "4.2"))
+        );
+    }
+
+    #[test]
+    fn test_numbered_lines() {
+        let lines: Vec<_> = numbered_lines("a\n\nb\n  \nc").collect();
+
+        assert_eq!(lines, vec![(1, "a"), (3, "b"), (5, "c")]);
+    }
+}
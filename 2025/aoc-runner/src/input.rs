@@ -0,0 +1,210 @@
+use std::env;
+use std::fmt::{Display, Formatter};
+use std::fs::read_to_string;
+use std::path::{Path, PathBuf};
+
+pub const INPUT_DIR_VAR: &str = "AOC_INPUT_DIR";
+
+/// Find the puzzle input of a day, wherever the binary is launched from.
+#[derive(Debug)]
+pub struct InputLocator {
+    explicit: Option<PathBuf>,
+    input_dir: Option<PathBuf>,
+    workspace_root: PathBuf,
+    current_dir: Option<PathBuf>,
+}
+
+#[derive(Debug, PartialEq)]
+pub enum InputError {
+    NotFound { day: u8, searched: Vec<PathBuf> },
+    Unreadable { path: PathBuf, reason: String },
+}
+
+impl Display for InputError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            InputError::NotFound { day, searched } => {
+                write!(f, "Input for day {day} not found, looked in:")?;
+                for path in searched {
+                    write!(f, "\n  - {}", path.display())?;
+                }
+                Ok(())
+            }
+            InputError::Unreadable { path, reason } => {
+                write!(f, "Cannot read input [{}]: {reason}", path.display())
+            }
+        }
+    }
+}
+
+impl std::error::Error for InputError {}
+
+impl InputLocator {
+    pub fn from_env(explicit: Option<PathBuf>) -> Self {
+        InputLocator {
+            explicit,
+            input_dir: env::var_os(INPUT_DIR_VAR).map(PathBuf::from),
+            workspace_root: Path::new(env!("CARGO_MANIFEST_DIR"))
+                .parent()
+                .map(Path::to_path_buf)
+                .unwrap_or_default(),
+            current_dir: env::current_dir().ok(),
+        }
+    }
+
+    pub fn candidates(&self, day: u8) -> Vec<PathBuf> {
+        if let Some(explicit) = &self.explicit {
+            return vec![explicit.clone()];
+        }
+
+        let crate_name = format!("day{day}");
+        let mut candidates = Vec::new();
+
+        if let Some(input_dir) = &self.input_dir {
+            candidates.push(input_dir.join(&crate_name).join("files").join("input"));
+            candidates.push(input_dir.join(&crate_name));
+        }
+
+        if let Some(current_dir) = &self.current_dir {
+            candidates.push(current_dir.join(&crate_name).join("files").join("input"));
+
+            if current_dir
+                .file_name()
+                .is_some_and(|name| name == crate_name.as_str())
+            {
+                candidates.push(current_dir.join("files").join("input"));
+            }
+        }
+
+        let from_root = self
+            .workspace_root
+            .join(&crate_name)
+            .join("files")
+            .join("input");
+        if !candidates.contains(&from_root) {
+            candidates.push(from_root);
+        }
+
+        candidates
+    }
+
+    pub fn locate(&self, day: u8) -> Result<PathBuf, InputError> {
+        let candidates = self.candidates(day);
+
+        candidates
+            .iter()
+            .find(|path| path.is_file())
+            .cloned()
+            .ok_or(InputError::NotFound {
+                day,
+                searched: candidates,
+            })
+    }
+
+    pub fn read(&self, day: u8) -> Result<String, InputError> {
+        let path = self.locate(day)?;
+
+        read_to_string(&path).map_err(|e| InputError::Unreadable {
+            path,
+            reason: e.to_string(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs::{create_dir_all, remove_dir_all, write};
+
+    fn scratch(name: &str) -> PathBuf {
+        let dir = env::temp_dir().join(format!("aoc-input-{}-{name}", std::process::id()));
+        let _ = remove_dir_all(&dir);
+        create_dir_all(&dir).expect("Cannot create scratch dir");
+        dir
+    }
+
+    fn locator(root: &Path) -> InputLocator {
+        InputLocator {
+            explicit: None,
+            input_dir: None,
+            workspace_root: root.to_path_buf(),
+            current_dir: None,
+        }
+    }
+
+    #[test]
+    fn test_locate_from_workspace_root() {
+        let root = scratch("root");
+        create_dir_all(root.join("day3/files")).unwrap();
+        write(root.join("day3/files/input"), "123").unwrap();
+
+        let locator = InputLocator {
+            current_dir: Some(root.clone()),
+            ..locator(Path::new("/nowhere"))
+        };
+
+        assert_eq!(locator.locate(3), Ok(root.join("day3/files/input")));
+        assert_eq!(locator.read(3), Ok(String::from("123")));
+    }
+
+    #[test]
+    fn test_locate_from_crate_dir() {
+        let root = scratch("crate");
+        create_dir_all(root.join("day1/files")).unwrap();
+        write(root.join("day1/files/input"), "L1").unwrap();
+
+        let locator = InputLocator {
+            current_dir: Some(root.join("day1")),
+            ..locator(Path::new("/nowhere"))
+        };
+
+        assert_eq!(locator.locate(1), Ok(root.join("day1/files/input")));
+        assert!(locator.locate(2).is_err());
+    }
+
+    #[test]
+    fn test_input_dir_takes_precedence() {
+        let root = scratch("env");
+        create_dir_all(root.join("inputs")).unwrap();
+        create_dir_all(root.join("day5/files")).unwrap();
+        write(root.join("inputs/day5"), "from env").unwrap();
+        write(root.join("day5/files/input"), "from root").unwrap();
+
+        let locator = InputLocator {
+            input_dir: Some(root.join("inputs")),
+            ..locator(&root)
+        };
+
+        assert_eq!(locator.read(5), Ok(String::from("from env")));
+    }
+
+    #[test]
+    fn test_explicit_path_only() {
+        let root = scratch("explicit");
+        create_dir_all(root.join("day5/files")).unwrap();
+        write(root.join("day5/files/input"), "from root").unwrap();
+
+        let locator = InputLocator {
+            explicit: Some(root.join("missing")),
+            ..locator(&root)
+        };
+
+        assert_eq!(
+            locator.locate(5),
+            Err(InputError::NotFound {
+                day: 5,
+                searched: vec![root.join("missing")]
+            })
+        );
+    }
+
+    #[test]
+    fn test_not_found_lists_searched_paths() {
+        let error = locator(Path::new("/root")).locate(7).unwrap_err();
+
+        assert_eq!(
+            error.to_string(),
+            "Input for day 7 not found, looked in:\n  - /root/day7/files/input"
+        );
+    }
+}
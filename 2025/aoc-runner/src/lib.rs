@@ -0,0 +1,185 @@
+mod error;
+mod input;
+
+pub use error::{Location, ParseError, SolveError, indentation, numbered_lines, parse_number};
+pub use input::{INPUT_DIR_VAR, InputError, InputLocator};
+use std::fmt::{Display, Formatter};
+use std::time::{Duration, Instant};
+
+/// A puzzle of one day: the input is parsed once and shared by both parts.
+pub trait Solution {
+    const DAY: u8;
+
+    type Input<'a>;
+    type Part1: Display;
+    type Part2: Display;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError>;
+
+    fn part1(input: &Self::Input<'_>) -> Result<Self::Part1, SolveError>;
+
+    fn part2(input: &Self::Input<'_>) -> Result<Self::Part2, SolveError>;
+}
+
+#[derive(Eq, PartialEq, Copy, Clone, Debug)]
+pub enum Part {
+    One,
+    Two,
+}
+
+#[derive(Debug)]
+pub struct Answer {
+    pub part: Part,
+    pub value: Result<String, SolveError>,
+    pub elapsed: Duration,
+}
+
+impl Display for Part {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Part::One => write!(f, "1"),
+            Part::Two => write!(f, "2"),
+        }
+    }
+}
+
+impl TryFrom<u8> for Part {
+    type Error = String;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            1 => Ok(Part::One),
+            2 => Ok(Part::Two),
+            part => Err(format!("Invalid part [{part}]")),
+        }
+    }
+}
+
+impl Display for Answer {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match &self.value {
+            Ok(value) => write!(
+                f,
+                "[{:?}] Sum for part {} is {value}",
+                self.elapsed, self.part
+            ),
+            Err(error) => write!(
+                f,
+                "[{:?}] No answer for part {}: {error}",
+                self.elapsed, self.part
+            ),
+        }
+    }
+}
+
+pub fn run<S: Solution>(input: &str, parts: &[Part]) -> Result<Vec<Answer>, ParseError> {
+    let parsed = S::parse(input).map_err(|e| e.on_day(S::DAY))?;
+
+    Ok(parts
+        .iter()
+        .map(|part| {
+            let start = Instant::now();
+            let value = match part {
+                Part::One => S::part1(&parsed).map(|answer| answer.to_string()),
+                Part::Two => S::part2(&parsed).map(|answer| answer.to_string()),
+            }
+            .map_err(|e| e.on_day(S::DAY));
+
+            Answer {
+                part: *part,
+                value,
+                elapsed: Instant::now() - start,
+            }
+        })
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Words;
+
+    impl Solution for Words {
+        const DAY: u8 = 42;
+
+        type Input<'a> = Vec<&'a str>;
+        type Part1 = usize;
+        type Part2 = String;
+
+        fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+            match input.find(|c: char| c.is_ascii_digit()) {
+                Some(index) => Err(ParseError::unexpected(index + 1, &input[index..], "a word")),
+                None => Ok(input.split_whitespace().collect()),
+            }
+        }
+
+        fn part1(input: &Self::Input<'_>) -> Result<Self::Part1, SolveError> {
+            Ok(input.len())
+        }
+
+        fn part2(input: &Self::Input<'_>) -> Result<Self::Part2, SolveError> {
+            match input.iter().find(|word| word.len() > 10) {
+                Some(word) => Err(SolveError::new(format!("[{word}] is too long"))),
+                None => Ok(input.concat()),
+            }
+        }
+    }
+
+    #[test]
+    fn test_part_from_number() {
+        assert_eq!(Part::try_from(1), Ok(Part::One));
+        assert_eq!(Part::try_from(2), Ok(Part::Two));
+        assert!(Part::try_from(3).is_err());
+    }
+
+    #[test]
+    fn test_run_selected_parts() {
+        let answers = run::<Words>("a b c", &[Part::Two]).expect("Invalid words");
+
+        assert_eq!(answers.len(), 1);
+        assert_eq!(answers[0].part, Part::Two);
+        assert_eq!(answers[0].value, Ok(String::from("abc")));
+
+        let answers = run::<Words>("a b c", &[Part::One, Part::Two]).expect("Invalid words");
+
+        assert_eq!(
+            answers
+                .iter()
+                .map(|a| a.value.as_deref())
+                .collect::<Vec<_>>(),
+            vec![Ok("3"), Ok("abc")]
+        );
+    }
+
+    #[test]
+    fn test_run_reports_failed_part() {
+        let answers =
+            run::<Words>("a supercalifragilistic", &[Part::One, Part::Two]).expect("Invalid words");
+
+        assert_eq!(answers[0].value, Ok(String::from("2")));
+        assert_eq!(
+            answers[1].value,
+            Err(SolveError::new("[supercalifragilistic] is too long").on_day(42))
+        );
+        assert!(
+            answers[1]
+                .to_string()
+                .ends_with("No answer for part 2: [day 42] [supercalifragilistic] is too long")
+        );
+    }
+
+    #[test]
+    fn test_run_reports_day_on_error() {
+        let error = run::<Words>("a 1", &[Part::One]).unwrap_err();
+
+        assert_eq!(
+            error.location(),
+            &Location {
+                day: 42,
+                line: 1,
+                column: 3
+            }
+        );
+    }
+}
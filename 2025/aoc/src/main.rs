@@ -0,0 +1,210 @@
+use aoc_runner::{Answer, InputLocator, ParseError, Part, Solution, run};
+use clap::{Parser, Subcommand};
+use std::fs::File;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+use std::thread;
+use std::time::Duration;
+
+#[derive(Parser, Debug)]
+#[command(name = "aoc", about = "Run the advent of code solutions")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand, Debug)]
+enum Command {
+    /// Solve the puzzle of the given day
+    Run {
+        year: u16,
+        day: u8,
+        /// Only run this part (1 or 2), both are run when omitted
+        #[arg(long, value_parser = parse_part)]
+        part: Option<Part>,
+        /// Read the puzzle input from this file instead of looking it up
+        #[arg(long)]
+        input: Option<PathBuf>,
+        /// Animate the puzzle in the terminal instead of solving it (2025 day 4 only)
+        #[arg(long, conflicts_with = "part")]
+        visualize: bool,
+        /// Milliseconds between two frames of the animation
+        #[arg(long, default_value_t = 200, requires = "visualize")]
+        delay: u64,
+        /// Also write every frame of the animation to this file, without colours
+        #[arg(long, requires = "visualize")]
+        frames: Option<PathBuf>,
+        /// Compare the totals of the worksheet read with these strategies, or `all` (2025 day 6 only)
+        #[arg(long, value_delimiter = ',', conflicts_with_all = ["part", "visualize"])]
+        reading: Vec<String>,
+    },
+}
+
+fn parse_part(value: &str) -> Result<Part, String> {
+    let number = value
+        .parse::<u8>()
+        .map_err(|_| format!("Invalid part [{value}]"))?;
+    Part::try_from(number)
+}
+
+type Solver = fn(&str, &[Part]) -> Result<Vec<Answer>, ParseError>;
+
+/// The solution of the day, checked before its input is looked up.
+fn solver(year: u16, day: u8) -> Result<Solver, String> {
+    match (year, day) {
+        (2025, 1) => Ok(run::<day1::Day1>),
+        (2025, 2) => Ok(run::<day2::Day2>),
+        (2025, 3) => Ok(run::<day3::Day3>),
+        (2025, 4) => Ok(run::<day4::Day4>),
+        (2025, 5) => Ok(run::<day5::Day5>),
+        (2025, 6) => Ok(run::<day6::Day6>),
+        (2025, 7) => Ok(run::<day7::Day7>),
+        (2025, 8) => Ok(run::<day8::Day8>),
+        (2025, 9) => Ok(run::<day9::Day9>),
+        (2025, 10) => Ok(run::<day10::Day10>),
+        (2025, 11) => Ok(run::<day11::Day11>),
+        _ => Err(format!("No solution for year {year} day {day}")),
+    }
+}
+
+/// Fail unless the day is the only one offering the feature.
+fn only_on(year: u16, day: u8, supported: (u16, u8), feature: &str) -> Result<(), String> {
+    if (year, day) == supported {
+        Ok(())
+    } else {
+        Err(format!("No {feature} for year {year} day {day}"))
+    }
+}
+
+fn read_input(input: Option<PathBuf>, day: u8) -> Result<String, String> {
+    InputLocator::from_env(input)
+        .read(day)
+        .map_err(|e| e.to_string())
+}
+
+fn visualize(day: u8, input: &str, delay: Duration, frames: Option<&Path>) -> Result<(), String> {
+    let shelf = day4::Day4::parse(input).map_err(|e| e.on_day(day).to_string())?;
+    let rule = day4::Rule::default();
+    let mut file = frames
+        .map(|path| {
+            File::create(path).map_err(|e| format!("Cannot write frames [{}]: {e}", path.display()))
+        })
+        .transpose()?;
+
+    for frame in day4::Cascade::new(&shelf, &rule) {
+        println!("\x1b[2J\x1b[H{}", frame.coloured());
+
+        if let Some(file) = &mut file {
+            writeln!(file, "{frame}\n").map_err(|e| format!("Cannot write frames: {e}"))?;
+        }
+
+        thread::sleep(delay);
+    }
+
+    Ok(())
+}
+
+fn compare_readings(day: u8, input: &str, names: &[String]) -> Result<Vec<String>, String> {
+    let strategies = day6::select(names).map_err(|e| e.to_string())?;
+    let totals =
+        day6::compare_readings(input, &strategies).map_err(|e| e.on_day(day).to_string())?;
+
+    Ok(totals
+        .iter()
+        .map(|(name, total)| format!("{name:<14} {total}"))
+        .collect())
+}
+
+fn report<T>(result: Result<T, String>, output: impl FnOnce(T)) -> ExitCode {
+    match result {
+        Ok(value) => {
+            output(value);
+            ExitCode::SUCCESS
+        }
+        Err(error) => {
+            eprintln!("{error}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+/// Print every answer, failing when a part has none.
+fn print_answers(answers: &[Answer]) -> ExitCode {
+    let mut code = ExitCode::SUCCESS;
+    for answer in answers {
+        if answer.value.is_ok() {
+            println!("{answer}");
+        } else {
+            eprintln!("{answer}");
+            code = ExitCode::FAILURE;
+        }
+    }
+    code
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+
+    match cli.command {
+        Command::Run {
+            year,
+            day,
+            input,
+            visualize: true,
+            delay,
+            frames,
+            ..
+        } => {
+            let animation = only_on(year, day, (2025, 4), "visualisation")
+                .and_then(|_| read_input(input, day))
+                .and_then(|content| {
+                    visualize(
+                        day,
+                        &content,
+                        Duration::from_millis(delay),
+                        frames.as_deref(),
+                    )
+                });
+
+            report(animation, |_| {})
+        }
+        Command::Run {
+            year,
+            day,
+            input,
+            reading,
+            ..
+        } if !reading.is_empty() => {
+            let totals = only_on(year, day, (2025, 6), "reading strategies")
+                .and_then(|_| read_input(input, day))
+                .and_then(|content| compare_readings(day, &content, &reading));
+
+            report(totals, |totals| {
+                totals.iter().for_each(|total| println!("{total}"))
+            })
+        }
+        Command::Run {
+            year,
+            day,
+            part,
+            input,
+            ..
+        } => {
+            let parts = match part {
+                Some(part) => vec![part],
+                None => vec![Part::One, Part::Two],
+            };
+
+            let answers = solver(year, day).and_then(|solve| {
+                let content = read_input(input, day)?;
+                solve(&content, &parts).map_err(|e| e.to_string())
+            });
+
+            match answers {
+                Ok(answers) => print_answers(&answers),
+                Err(error) => report::<()>(Err(error), |_| {}),
+            }
+        }
+    }
+}
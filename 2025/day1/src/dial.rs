@@ -0,0 +1,199 @@
+use crate::Movement;
+use std::fmt::{Display, Formatter};
+
+/// What makes the dial count towards the code.
+#[derive(Eq, PartialEq, Copy, Clone, Debug)]
+pub enum Counting {
+    /// Once per movement ending on zero.
+    Landing,
+    /// Every time zero is reached, including during a movement.
+    Passing,
+}
+
+#[derive(Debug, Eq, PartialEq, Copy, Clone)]
+pub struct State {
+    pub code: i64,
+    pub position: i64,
+}
+
+impl Display for State {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "[code: {}, position: {}]", self.code, self.position)
+    }
+}
+
+/// A dial numbered from `0` to `size - 1`, turning left to lower numbers.
+#[derive(Debug, Eq, PartialEq, Copy, Clone)]
+pub struct Dial {
+    size: i64,
+    start: i64,
+    counting: Counting,
+}
+
+#[derive(Debug, Eq, PartialEq, Copy, Clone)]
+pub enum DialError {
+    NoPosition,
+    StartOutside { start: i64, size: i64 },
+}
+
+impl Display for DialError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            DialError::NoPosition => write!(f, "The dial needs at least one position"),
+            DialError::StartOutside { start, size } => write!(
+                f,
+                "The dial cannot start on {start}, its positions go from 0 to {}",
+                size - 1
+            ),
+        }
+    }
+}
+
+impl std::error::Error for DialError {}
+
+impl Dial {
+    pub fn new(size: i64, start: i64, counting: Counting) -> Result<Self, DialError> {
+        if size <= 0 {
+            return Err(DialError::NoPosition);
+        }
+        if !(0..size).contains(&start) {
+            return Err(DialError::StartOutside { start, size });
+        }
+
+        Ok(Dial {
+            size,
+            start,
+            counting,
+        })
+    }
+
+    /// The dial of the safe, 100 positions starting at 50.
+    pub fn safe(counting: Counting) -> Self {
+        Dial {
+            size: 100,
+            start: 50,
+            counting,
+        }
+    }
+
+    pub fn start(&self) -> State {
+        State {
+            code: 0,
+            position: self.start,
+        }
+    }
+
+    pub fn apply(&self, movements: &[Movement]) -> State {
+        movements
+            .iter()
+            .fold(self.start(), |state, movement| self.turn(state, movement))
+    }
+
+    /// State after each movement.
+    pub fn trace<'a>(&self, movements: &'a [Movement]) -> impl Iterator<Item = State> + 'a {
+        let dial = *self;
+        movements.iter().scan(self.start(), move |state, movement| {
+            *state = dial.turn(*state, movement);
+            Some(*state)
+        })
+    }
+
+    fn turn(&self, state: State, movement: &Movement) -> State {
+        let position = (state.position + movement.step).rem_euclid(self.size);
+
+        let zeros = match self.counting {
+            Counting::Landing => (position == 0) as i64,
+            Counting::Passing if movement.step >= 0 => (state.position + movement.step) / self.size,
+            // Turning left from `p` reaches zero like turning right from `size - p`.
+            Counting::Passing => {
+                ((self.size - state.position) % self.size - movement.step) / self.size
+            }
+        };
+
+        State {
+            code: state.code + zeros,
+            position,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::str::FromStr;
+
+    fn movements(data: &[&str]) -> Vec<Movement> {
+        data.iter()
+            .map(|movement| Movement::from_str(movement).expect("Invalid movement"))
+            .collect()
+    }
+
+    #[test]
+    fn test_trace() {
+        let movements = movements(&[
+            "L68", "L30", "R48", "L5", "R60", "L55", "L1", "L99", "R14", "L82",
+        ]);
+
+        let positions: Vec<i64> = Dial::safe(Counting::Landing)
+            .trace(&movements)
+            .map(|state| state.position)
+            .collect();
+        assert_eq!(positions, vec![82, 52, 0, 95, 55, 0, 99, 0, 14, 32]);
+
+        let codes: Vec<i64> = Dial::safe(Counting::Passing)
+            .trace(&movements)
+            .map(|state| state.code)
+            .collect();
+        assert_eq!(codes, vec![1, 1, 2, 2, 3, 4, 4, 5, 5, 6]);
+    }
+
+    #[test]
+    fn test_small_dial() {
+        let movements = movements(&["R7", "L3", "L4"]);
+        let dial = |start, counting| Dial::new(5, start, counting).expect("Invalid dial");
+
+        assert_eq!(
+            dial(0, Counting::Passing).apply(&movements),
+            State {
+                code: 3,
+                position: 0
+            }
+        );
+        assert_eq!(
+            dial(0, Counting::Landing).apply(&movements),
+            State {
+                code: 1,
+                position: 0
+            }
+        );
+        assert_eq!(
+            dial(3, Counting::Passing).apply(&[]),
+            dial(3, Counting::Passing).start()
+        );
+    }
+
+    #[test]
+    fn test_invalid_dial() {
+        assert_eq!(
+            Dial::new(0, 0, Counting::Landing),
+            Err(DialError::NoPosition)
+        );
+        assert_eq!(
+            Dial::new(5, 5, Counting::Landing),
+            Err(DialError::StartOutside { start: 5, size: 5 })
+        );
+    }
+
+    #[test]
+    fn test_long_turns() {
+        let movements = movements(&["R4294967295", "L4294967295", "L4294967295"]);
+
+        assert_eq!(
+            Dial::safe(Counting::Passing).apply(&movements),
+            State {
+                code: 128849019,
+                position: 55
+            }
+        );
+    }
+}
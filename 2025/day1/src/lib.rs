@@ -0,0 +1,120 @@
+mod dial;
+
+pub use dial::{Counting, Dial, DialError, State};
+
+use aoc_runner::{ParseError, Solution, SolveError, numbered_lines, parse_number};
+use std::str::FromStr;
+
+pub struct Day1;
+
+#[derive(Debug)]
+pub struct Movement {
+    step: i64,
+}
+
+impl FromStr for Movement {
+    type Err = ParseError;
+
+    fn from_str(raw: &str) -> Result<Self, ParseError> {
+        let (direction, number) =
+            raw.split_at_checked(1)
+                .ok_or(ParseError::missing(1, raw, "a direction"))?;
+        let step = parse_number::<u32>(number, 2)? as i64;
+
+        let step = match direction {
+            "L" => -step,
+            "R" => step,
+            _ => return Err(ParseError::unexpected(1, direction, "'L' or 'R'")),
+        };
+
+        Ok(Movement { step })
+    }
+}
+
+impl Solution for Day1 {
+    const DAY: u8 = 1;
+
+    type Input<'a> = Vec<Movement>;
+    type Part1 = i64;
+    type Part2 = i64;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        numbered_lines(input)
+            .map(|(line, movement)| Movement::from_str(movement).map_err(|e| e.at_line(line)))
+            .collect()
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Result<Self::Part1, SolveError> {
+        Ok(Dial::safe(Counting::Landing).apply(input).code)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Result<Self::Part2, SolveError> {
+        Ok(Dial::safe(Counting::Passing).apply(input).code)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn movements(data: &[&str]) -> Vec<Movement> {
+        data.iter()
+            .map(|movement| Movement::from_str(movement).expect("Invalid movement"))
+            .collect()
+    }
+
+    #[test]
+    fn test_with_example_part_1() {
+        let test_data = "L68\nL30\nR48\nL5\nR60\nL55\nL1\nL99\nR14\nL82";
+        let movements = Day1::parse(test_data).expect("Invalid movements");
+
+        assert_eq!(Day1::part1(&movements), Ok(3));
+    }
+
+    #[test]
+    fn test_with_example_part_2() {
+        let test_data = "L68\nL30\nR48\nL5\nR60\nL55\nL1\nL99\nR14\nL82";
+        let movements = Day1::parse(test_data).expect("Invalid movements");
+
+        assert_eq!(Day1::part2(&movements), Ok(6));
+    }
+
+    #[test]
+    fn test_multiple_turn_v2() {
+        let test_data = vec!["L50", "R1000"];
+        assert_eq!(
+            Dial::safe(Counting::Passing)
+                .apply(&movements(&test_data))
+                .code,
+            11
+        );
+
+        let test_data = vec!["R50", "L1000"];
+        assert_eq!(
+            Dial::safe(Counting::Passing)
+                .apply(&movements(&test_data))
+                .code,
+            11
+        );
+    }
+
+    #[test]
+    fn test_invalid_movement() {
+        assert_eq!(
+            Day1::parse("L68\nR4x").unwrap_err(),
+            ParseError::invalid_number(2, "4x").at_line(2)
+        );
+        assert_eq!(
+            Movement::from_str("").unwrap_err(),
+            ParseError::missing(1, "", "a direction")
+        );
+        assert_eq!(
+            Movement::from_str("L-5").unwrap_err(),
+            ParseError::invalid_number(2, "-5")
+        );
+        assert_eq!(
+            Day1::parse("L68\nU4").unwrap_err(),
+            ParseError::unexpected(1, "U", "'L' or 'R'").at_line(2)
+        );
+    }
+}
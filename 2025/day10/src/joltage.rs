@@ -0,0 +1,208 @@
+use crate::Button;
+
+/// Solve `A x = b` for the smallest `x` in natural numbers, where each button is a 0/1 column of `A`.
+///
+/// The system is reduced with a fraction-free Gaussian elimination, then the free
+/// variables are enumerated between zero and the smallest counter they increase.
+/// Buttons must only increase existing counters, as checked when machines are parsed.
+pub(crate) fn minimum_presses(buttons: &[Button], wanted: &[i32]) -> Option<Vec<u64>> {
+    let system = System::new(buttons, wanted)?;
+
+    let bounds: Vec<i64> = system
+        .free
+        .iter()
+        .map(|column| {
+            buttons[*column]
+                .index
+                .iter()
+                .map(|counter| wanted[*counter])
+                .min()
+                .unwrap_or(0) as i64
+        })
+        .collect();
+
+    let mut best: Option<(i64, Vec<i64>)> = None;
+    let mut assignment = vec![0; system.free.len()];
+    system.search(&bounds, 0, 0, &mut assignment, &mut best);
+
+    best.map(|(_, presses)| presses.into_iter().map(|p| p as u64).collect())
+}
+
+struct System {
+    rows: Vec<Vec<i64>>,
+    pivots: Vec<usize>,
+    free: Vec<usize>,
+    size: usize,
+}
+
+impl System {
+    fn new(buttons: &[Button], wanted: &[i32]) -> Option<System> {
+        let size = buttons.len();
+        let mut rows: Vec<Vec<i64>> = wanted
+            .iter()
+            .enumerate()
+            .map(|(counter, joltage)| {
+                let mut row: Vec<i64> = buttons
+                    .iter()
+                    .map(|button| button.index.contains(&counter) as i64)
+                    .collect();
+                row.push(*joltage as i64);
+                row
+            })
+            .collect();
+
+        let mut pivots = Vec::new();
+        let mut free = Vec::new();
+        for column in 0..size {
+            let rank = pivots.len();
+            let Some(pivot) = (rank..rows.len()).find(|row| rows[*row][column] != 0) else {
+                free.push(column);
+                continue;
+            };
+            rows.swap(rank, pivot);
+
+            for row in 0..rows.len() {
+                let factor = rows[row][column];
+                if row == rank || factor == 0 {
+                    continue;
+                }
+
+                let pivot_value = rows[rank][column];
+                let reduced: Vec<i64> = rows[row]
+                    .iter()
+                    .zip(&rows[rank])
+                    .map(|(value, pivot_row)| value * pivot_value - pivot_row * factor)
+                    .collect();
+                rows[row] = normalize(reduced);
+            }
+
+            pivots.push(column);
+        }
+
+        if rows[pivots.len()..].iter().any(|row| row[size] != 0) {
+            return None;
+        }
+        rows.truncate(pivots.len());
+
+        Some(System {
+            rows,
+            pivots,
+            free,
+            size,
+        })
+    }
+
+    fn search(
+        &self,
+        bounds: &[i64],
+        depth: usize,
+        total: i64,
+        assignment: &mut Vec<i64>,
+        best: &mut Option<(i64, Vec<i64>)>,
+    ) {
+        if best
+            .as_ref()
+            .is_some_and(|(best_total, _)| total >= *best_total)
+        {
+            return;
+        }
+
+        if depth == self.free.len() {
+            if let Some(presses) = self.resolve(assignment) {
+                let total = presses.iter().sum();
+                if best
+                    .as_ref()
+                    .is_none_or(|(best_total, _)| total < *best_total)
+                {
+                    *best = Some((total, presses));
+                }
+            }
+            return;
+        }
+
+        for value in 0..=bounds[depth] {
+            assignment[depth] = value;
+            self.search(bounds, depth + 1, total + value, assignment, best);
+        }
+    }
+
+    fn resolve(&self, assignment: &[i64]) -> Option<Vec<i64>> {
+        let mut presses = vec![0; self.size];
+        for (column, value) in self.free.iter().zip(assignment) {
+            presses[*column] = *value;
+        }
+
+        for (row, column) in self.rows.iter().zip(&self.pivots) {
+            let remaining = row[self.size]
+                - self
+                    .free
+                    .iter()
+                    .zip(assignment)
+                    .map(|(free, value)| row[*free] * value)
+                    .sum::<i64>();
+
+            let pivot = row[*column];
+            if remaining % pivot != 0 || remaining / pivot < 0 {
+                return None;
+            }
+            presses[*column] = remaining / pivot;
+        }
+
+        Some(presses)
+    }
+}
+
+fn normalize(row: Vec<i64>) -> Vec<i64> {
+    let divisor = row.iter().fold(0, |acc, value| gcd(acc, value.abs()));
+    if divisor <= 1 {
+        return row;
+    }
+
+    row.into_iter().map(|value| value / divisor).collect()
+}
+
+fn gcd(a: i64, b: i64) -> i64 {
+    if b == 0 { a } else { gcd(b, a % b) }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::buttons;
+
+    fn apply(buttons: &[Button], presses: &[u64], size: usize) -> Vec<i32> {
+        let mut counters = vec![0; size];
+        for (button, count) in buttons.iter().zip(presses) {
+            button
+                .index
+                .iter()
+                .for_each(|counter| counters[*counter] += *count as i32);
+        }
+        counters
+    }
+
+    #[test]
+    fn test_minimum_presses() {
+        let buttons = buttons(&[&[3], &[1, 3], &[2], &[2, 3], &[0, 2], &[0, 1]]);
+        let wanted = [3, 5, 4, 7];
+
+        let presses = minimum_presses(&buttons, &wanted).expect("No solution");
+
+        assert_eq!(presses.iter().sum::<u64>(), 10);
+        assert_eq!(apply(&buttons, &presses, wanted.len()), wanted);
+    }
+
+    #[test]
+    fn test_no_solution() {
+        let buttons = buttons(&[&[0, 1]]);
+
+        assert_eq!(minimum_presses(&buttons, &[1, 2]), None);
+    }
+
+    #[test]
+    fn test_without_free_variable() {
+        let buttons = buttons(&[&[0], &[0, 1], &[2]]);
+
+        assert_eq!(minimum_presses(&buttons, &[5, 3, 2]), Some(vec![2, 3, 2]));
+    }
+}
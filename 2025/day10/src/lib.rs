@@ -0,0 +1,387 @@
+mod joltage;
+mod lights;
+
+pub use lights::{MAX_FREE_BUTTONS, ToggleError};
+
+use aoc_runner::{ParseError, Solution, SolveError, numbered_lines, parse_number};
+use std::cmp::PartialEq;
+use std::str::FromStr;
+
+#[derive(Debug, Eq, PartialEq, Clone)]
+struct Button {
+    index: Vec<usize>,
+}
+
+/// Buttons wired to the given lights and counters.
+#[cfg(test)]
+fn buttons(indexes: &[&[usize]]) -> Vec<Button> {
+    indexes
+        .iter()
+        .map(|index| Button {
+            index: index.to_vec(),
+        })
+        .collect()
+}
+
+impl FromStr for Button {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(Button {
+            index: parse_list(s, '(', ')', "a button between [()]")?,
+        })
+    }
+}
+
+#[derive(Debug)]
+pub struct Machine {
+    wanted_state: Vec<bool>,
+    buttons: Vec<Button>,
+    wanted_joltage: Vec<i32>,
+}
+
+impl FromStr for Machine {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut offset = 0;
+        let mut parts: Vec<(usize, &str)> = Vec::new();
+        for part in s.split(' ') {
+            if !part.is_empty() {
+                parts.push((offset, part));
+            }
+            offset += part.len() + 1;
+        }
+
+        let (state, others) =
+            parts
+                .split_first()
+                .ok_or(ParseError::missing(1, s, "a light diagram"))?;
+        let (joltage, buttons) = others.split_last().ok_or(ParseError::missing(
+            s.len() + 1,
+            s,
+            "joltage requirements",
+        ))?;
+
+        let wanted_state = parse_lights(state.1).map_err(|e| e.shifted(state.0))?;
+
+        let buttons: Vec<Button> = buttons
+            .iter()
+            .map(|(offset, b)| Button::from_str(b).map_err(|e| e.shifted(*offset)))
+            .collect::<Result<_, _>>()?;
+
+        let joltage: Vec<i32> = parse_list(joltage.1, '{', '}', "joltage between [{}]")
+            .map_err(|e| e.shifted(joltage.0))?;
+
+        let counters = joltage.len().min(wanted_state.len());
+        if let Some((offset, text)) = others[..others.len() - 1]
+            .iter()
+            .zip(&buttons)
+            .find(|(_, button)| button.index.iter().any(|index| *index >= counters))
+            .map(|(part, _)| part)
+        {
+            return Err(ParseError::unexpected(
+                1,
+                text,
+                "a button wired to existing lights and counters",
+            )
+            .shifted(*offset));
+        }
+
+        Ok(Machine {
+            wanted_state,
+            buttons,
+            wanted_joltage: joltage,
+        })
+    }
+}
+
+fn parse_lights(s: &str) -> Result<Vec<bool>, ParseError> {
+    let lights = s
+        .strip_prefix('[')
+        .and_then(|s| s.strip_suffix(']'))
+        .ok_or(ParseError::unexpected(1, s, "a light diagram between [[]]"))?;
+
+    lights
+        .char_indices()
+        .map(|(index, c)| match c {
+            '#' => Ok(true),
+            '.' => Ok(false),
+            _ => Err(ParseError::unexpected(
+                index + 2,
+                &c.to_string(),
+                "'.' or '#'",
+            )),
+        })
+        .collect()
+}
+
+fn parse_list<T: FromStr>(
+    s: &str,
+    open: char,
+    close: char,
+    expected: &'static str,
+) -> Result<Vec<T>, ParseError> {
+    let content = s
+        .strip_prefix(open)
+        .and_then(|s| s.strip_suffix(close))
+        .ok_or(ParseError::unexpected(1, s, expected))?;
+
+    let mut column = 2;
+    content
+        .split(',')
+        .map(|number| {
+            let parsed = parse_number(number, column);
+            column += number.len() + 1;
+            parsed
+        })
+        .collect()
+}
+
+impl Machine {
+    /// Buttons to press once each to turn the lights into the wanted state.
+    pub fn toggle_lights(&self) -> Result<Vec<usize>, ToggleError> {
+        lights::minimum_toggles(&self.buttons, &self.wanted_state)
+    }
+
+    /// Presses of each button reaching the wanted joltage with the fewest presses in total.
+    pub fn configure_joltage(&self) -> Option<Vec<u64>> {
+        joltage::minimum_presses(&self.buttons, &self.wanted_joltage)
+    }
+}
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    const DAY: u8 = 10;
+
+    type Input<'a> = Vec<Machine>;
+    type Part1 = usize;
+    type Part2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Result<Self::Part1, SolveError> {
+        input
+            .iter()
+            .enumerate()
+            .map(|(index, machine)| {
+                machine
+                    .toggle_lights()
+                    .map(|pressed| pressed.len())
+                    .map_err(|e| SolveError::new(format!("Machine {}: {e}", index + 1)))
+            })
+            .sum()
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Result<Self::Part2, SolveError> {
+        input
+            .iter()
+            .enumerate()
+            .map(|(index, machine)| {
+                machine
+                    .configure_joltage()
+                    .map(|presses| presses.iter().sum::<u64>())
+                    .ok_or_else(|| {
+                        SolveError::new(format!(
+                            "No button presses reach the joltage of machine {}",
+                            index + 1
+                        ))
+                    })
+            })
+            .sum()
+    }
+}
+
+fn parse(input: &str) -> Result<Vec<Machine>, ParseError> {
+    numbered_lines(input)
+        .map(|(line_number, line)| Machine::from_str(line).map_err(|e| e.at_line(line_number)))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::collection::vec;
+    use proptest::prelude::*;
+    use std::collections::HashSet;
+
+    type StateLight = (Vec<bool>, Option<Button>, usize);
+
+    fn push_buttons_bfs(machine: &Machine) -> i32 {
+        let mut states: Vec<StateLight> = vec![(vec![false; machine.wanted_state.len()], None, 0)];
+        let mut visited: HashSet<Vec<bool>> = HashSet::new();
+
+        for _ in 0..2 << machine.wanted_state.len() {
+            let mut new_states: Vec<StateLight> = vec![];
+            for state in states {
+                for button in machine.buttons.iter() {
+                    if let Some(previous) = state.1.as_ref()
+                        && previous == button
+                    {
+                        continue;
+                    }
+
+                    let new_state: StateLight = (
+                        state
+                            .0
+                            .iter()
+                            .enumerate()
+                            .map(|(index, previous)| {
+                                if button.index.contains(&index) {
+                                    !*previous
+                                } else {
+                                    *previous
+                                }
+                            })
+                            .collect(),
+                        Some(button.clone()),
+                        state.2 + 1,
+                    );
+
+                    if !visited.insert(new_state.0.clone()) {
+                        continue;
+                    }
+
+                    if new_state.0 == machine.wanted_state {
+                        return new_state.2 as i32;
+                    }
+
+                    new_states.push(new_state);
+                }
+            }
+
+            states = new_states;
+        }
+        0
+    }
+
+    static TEST_DATA: &str = "[.##.] (3) (1,3) (2) (2,3) (0,2) (0,1) {3,5,4,7}
+[...#.] (0,2,3,4) (2,3) (0,4) (0,1,2) (1,2,3,4) {7,5,12,7,2}
+[.###.#] (0,1,2,3,4) (0,3,4) (0,1,2,4,5) (1,2) {10,11,11,5,10,5}";
+
+    #[test]
+    fn test_example_part_1() {
+        let machines = parse(TEST_DATA).expect("Invalid machines");
+
+        assert_eq!(Day10::part1(&machines), Ok(7));
+    }
+
+    #[test]
+    fn test_unreachable_lights() {
+        let machines = parse("[#.] (0) {1,1}\n[#.] (0,1) {1,1}").expect("Invalid machines");
+
+        assert_eq!(
+            Day10::part1(&machines),
+            Err(SolveError::new(
+                "Machine 2: No buttons turn the lights into the wanted state"
+            ))
+        );
+    }
+
+    fn machine() -> impl Strategy<Value = Machine> {
+        (2usize..8)
+            .prop_flat_map(|lights| {
+                (
+                    vec(any::<bool>(), lights),
+                    vec(vec(any::<bool>(), lights), 1..7),
+                )
+            })
+            .prop_map(|(wanted_state, wiring)| Machine {
+                wanted_state,
+                buttons: wiring
+                    .iter()
+                    .map(|wires| Button {
+                        index: (0..wires.len()).filter(|light| wires[*light]).collect(),
+                    })
+                    .collect(),
+                wanted_joltage: vec![],
+            })
+    }
+
+    proptest! {
+        #[test]
+        fn test_toggle_lights_matches_bfs(machine in machine()) {
+            let expected = push_buttons_bfs(&machine);
+
+            match machine.toggle_lights() {
+                Ok(pressed) if machine.wanted_state.iter().all(|on| !on) => {
+                    prop_assert_eq!(pressed, vec![])
+                }
+                Ok(pressed) => prop_assert_eq!(pressed.len() as i32, expected),
+                Err(error) => {
+                    prop_assert_eq!(error, ToggleError::Unreachable);
+                    prop_assert_eq!(expected, 0);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_example_part_2() {
+        let machines = parse(TEST_DATA).expect("Invalid machines");
+
+        assert_eq!(Day10::part2(&machines), Ok(33));
+    }
+
+    #[test]
+    fn test_unreachable_joltage() {
+        let machines = parse("[#.] (0) (1) {3,5}\n[##] (0,1) {1,2}").expect("Invalid machines");
+
+        assert_eq!(machines[0].configure_joltage(), Some(vec![3, 5]));
+        assert_eq!(machines[1].configure_joltage(), None);
+        assert_eq!(
+            Day10::part2(&machines),
+            Err(SolveError::new(
+                "No button presses reach the joltage of machine 2"
+            ))
+        );
+    }
+
+    #[test]
+    fn test_configure_joltage() {
+        let machines = parse(TEST_DATA).expect("Invalid machines");
+
+        let presses: Vec<u64> = machines
+            .iter()
+            .map(|m| {
+                m.configure_joltage()
+                    .expect("No configuration")
+                    .iter()
+                    .sum()
+            })
+            .collect();
+
+        assert_eq!(presses, vec![10, 12, 11]);
+    }
+
+    #[test]
+    fn test_parse_invalid_machine() {
+        assert_eq!(
+            Machine::from_str("[.##.] (3) (1,x) {3,5,4,7}").unwrap_err(),
+            ParseError::invalid_number(15, "x")
+        );
+        assert_eq!(
+            Machine::from_str("[.#*.] (3) {3,5,4,7}").unwrap_err(),
+            ParseError::unexpected(4, "*", "'.' or '#'")
+        );
+        assert_eq!(
+            Machine::from_str("[.##.] (3) 1,2 {3,5,4,7}").unwrap_err(),
+            ParseError::unexpected(12, "1,2", "a button between [()]")
+        );
+        assert_eq!(
+            Machine::from_str("[.##.] (3) (1,4) {3,5,4,7}").unwrap_err(),
+            ParseError::unexpected(
+                12,
+                "(1,4)",
+                "a button wired to existing lights and counters"
+            )
+        );
+        assert_eq!(
+            parse("[.##.] (3) {3,5,4,7}\n[.##.]").unwrap_err(),
+            ParseError::missing(7, "[.##.]", "joltage requirements").at_line(2)
+        );
+    }
+}
@@ -0,0 +1,207 @@
+use crate::Button;
+use std::fmt::{Display, Formatter};
+
+/// Buttons independent of the others whose combinations are all tried, `2^20` at most.
+pub const MAX_FREE_BUTTONS: usize = 20;
+
+#[derive(Debug, Eq, PartialEq, Copy, Clone)]
+pub enum ToggleError {
+    /// No combination of buttons turns the lights into the wanted state.
+    Unreachable,
+    /// Too many free buttons to try all their combinations.
+    TooManyFreeButtons(usize),
+}
+
+impl Display for ToggleError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ToggleError::Unreachable => {
+                write!(f, "No buttons turn the lights into the wanted state")
+            }
+            ToggleError::TooManyFreeButtons(free) => write!(
+                f,
+                "{free} free buttons, more than the {MAX_FREE_BUTTONS} that can be tried"
+            ),
+        }
+    }
+}
+
+impl std::error::Error for ToggleError {}
+
+/// Bit set of buttons.
+#[derive(Debug, Eq, PartialEq, Clone)]
+struct Bits(Vec<u64>);
+
+impl Bits {
+    fn new(len: usize) -> Self {
+        Bits(vec![0; len.div_ceil(64)])
+    }
+
+    fn insert(&mut self, index: usize) {
+        self.0[index / 64] |= 1 << (index % 64);
+    }
+
+    fn contains(&self, index: usize) -> bool {
+        self.0[index / 64] & 1 << (index % 64) != 0
+    }
+
+    fn toggle(&mut self, other: &Bits) {
+        self.0
+            .iter_mut()
+            .zip(&other.0)
+            .for_each(|(word, other)| *word ^= other);
+    }
+
+    fn len(&self) -> u32 {
+        self.0.iter().map(|word| word.count_ones()).sum()
+    }
+
+    /// Whether an odd number of buttons are in both sets.
+    fn odd_overlap(&self, other: &Bits) -> bool {
+        self.0
+            .iter()
+            .zip(&other.0)
+            .map(|(word, other)| (word & other).count_ones())
+            .sum::<u32>()
+            % 2
+            == 1
+    }
+}
+
+/// Find the smallest set of buttons turning the lights into the wanted state.
+///
+/// Each light is an equation over GF(2) where the buttons are bits of a mask, the
+/// system is reduced by Gaussian elimination and every vector of its null space is
+/// tried to keep the solution pressing the fewest buttons.
+pub(crate) fn minimum_toggles(
+    buttons: &[Button],
+    wanted: &[bool],
+) -> Result<Vec<usize>, ToggleError> {
+    let mut rows: Vec<(Bits, bool)> = wanted
+        .iter()
+        .enumerate()
+        .map(|(light, on)| {
+            let mut mask = Bits::new(buttons.len());
+            buttons
+                .iter()
+                .enumerate()
+                .filter(|(_, button)| button.index.contains(&light))
+                .for_each(|(index, _)| mask.insert(index));
+            (mask, *on)
+        })
+        .collect();
+
+    let mut pivots: Vec<usize> = Vec::new();
+    let mut free: Vec<usize> = Vec::new();
+    for column in 0..buttons.len() {
+        let rank = pivots.len();
+        let Some(pivot) = (rank..rows.len()).find(|row| rows[*row].0.contains(column)) else {
+            free.push(column);
+            continue;
+        };
+        rows.swap(rank, pivot);
+
+        let (pivot_mask, pivot_on) = rows[rank].clone();
+        for (index, row) in rows.iter_mut().enumerate() {
+            if index != rank && row.0.contains(column) {
+                row.0.toggle(&pivot_mask);
+                row.1 ^= pivot_on;
+            }
+        }
+
+        pivots.push(column);
+    }
+
+    if rows[pivots.len()..].iter().any(|(_, on)| *on) {
+        return Err(ToggleError::Unreachable);
+    }
+    if free.len() > MAX_FREE_BUTTONS {
+        return Err(ToggleError::TooManyFreeButtons(free.len()));
+    }
+
+    let best = (0..1u32 << free.len())
+        .map(|combination| {
+            let mut mask = Bits::new(buttons.len());
+            free.iter()
+                .enumerate()
+                .filter(|(index, _)| combination & 1 << index != 0)
+                .for_each(|(_, column)| mask.insert(*column));
+
+            let free_mask = mask.clone();
+            pivots
+                .iter()
+                .zip(&rows)
+                .filter(|(_, (row, on))| on ^ row.odd_overlap(&free_mask))
+                .for_each(|(column, _)| mask.insert(*column));
+            mask
+        })
+        .min_by_key(Bits::len)
+        .ok_or(ToggleError::Unreachable)?;
+
+    Ok((0..buttons.len())
+        .filter(|index| best.contains(*index))
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::buttons;
+
+    #[test]
+    fn test_minimum_toggles() {
+        let buttons = buttons(&[&[3], &[1, 3], &[2], &[2, 3], &[0, 2], &[0, 1]]);
+
+        let pressed = minimum_toggles(&buttons, &[false, true, true, false]).expect("No solution");
+
+        assert_eq!(pressed.len(), 2);
+
+        let mut lights = [false; 4];
+        pressed
+            .iter()
+            .flat_map(|button| &buttons[*button].index)
+            .for_each(|light| lights[*light] = !lights[*light]);
+        assert_eq!(lights, [false, true, true, false]);
+    }
+
+    #[test]
+    fn test_already_in_state() {
+        let buttons = buttons(&[&[0], &[1]]);
+
+        assert_eq!(minimum_toggles(&buttons, &[false, false]), Ok(vec![]));
+    }
+
+    #[test]
+    fn test_no_solution() {
+        let buttons = buttons(&[&[0, 1]]);
+
+        assert_eq!(
+            minimum_toggles(&buttons, &[true, false]),
+            Err(ToggleError::Unreachable)
+        );
+    }
+
+    #[test]
+    fn test_more_than_64_buttons() {
+        let indexes: Vec<[usize; 1]> = (0..70).map(|light| [light]).collect();
+        let indexes: Vec<&[usize]> = indexes.iter().map(|index| index.as_slice()).collect();
+        let mut wanted = vec![false; 70];
+        wanted[3] = true;
+        wanted[68] = true;
+
+        assert_eq!(
+            minimum_toggles(&buttons(&indexes), &wanted),
+            Ok(vec![3, 68])
+        );
+    }
+
+    #[test]
+    fn test_too_many_free_buttons() {
+        let buttons = buttons(&[&[0usize][..]; 30]);
+
+        assert_eq!(
+            minimum_toggles(&buttons, &[true]),
+            Err(ToggleError::TooManyFreeButtons(29))
+        );
+    }
+}
@@ -0,0 +1,162 @@
+use aoc_runner::{ParseError, Solution, SolveError, numbered_lines};
+use std::{collections::HashMap, vec};
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    const DAY: u8 = 11;
+
+    type Input<'a> = HashMap<&'a str, Vec<&'a str>>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse_v2(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Result<Self::Part1, SolveError> {
+        find_path_v2("you", "out", &[], input)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Result<Self::Part2, SolveError> {
+        find_path_v2("svr", "out", &["fft", "dac"], input)
+    }
+}
+
+fn parse_v2(input: &str) -> Result<HashMap<&str, Vec<&str>>, ParseError> {
+    let mut nodes: HashMap<&str, Vec<&str>> = HashMap::new();
+
+    for (line_number, line) in numbered_lines(input) {
+        let (begin, others) = line
+            .split_once(":")
+            .ok_or(ParseError::missing(line.len() + 1, line, "':'").at_line(line_number))?;
+        nodes.insert(begin, others.split_whitespace().collect());
+    }
+
+    Ok(nodes)
+}
+
+fn find_path_v2(
+    from: &str,
+    to: &str,
+    via: &[&str],
+    devices: &HashMap<&str, Vec<&str>>,
+) -> Result<usize, SolveError> {
+    walk_dfs(
+        from,
+        to,
+        via,
+        devices,
+        &vec![false; via.len()],
+        &mut HashMap::new(),
+    )
+}
+
+fn walk_dfs<'a>(
+    from: &str,
+    to: &str,
+    via: &[&str],
+    devices: &HashMap<&str, Vec<&'a str>>,
+    pass: &[bool],
+    mem: &mut HashMap<(&'a str, Vec<bool>), usize>,
+) -> Result<usize, SolveError> {
+    if from == to {
+        return Ok(match pass.iter().all(|x| *x) {
+            true => 1,
+            false => 0,
+        });
+    }
+
+    devices
+        .get(from)
+        .ok_or_else(|| SolveError::new(format!("Device `{from}` not found")))?
+        .iter()
+        .map(|next| {
+            let new_pass: Vec<bool> = pass
+                .iter()
+                .zip(via)
+                .map(|(passed, wanted)| *passed || wanted == next)
+                .collect();
+
+            if let Some(existing) = mem.get(&(*next, new_pass.clone())) {
+                return Ok(*existing);
+            }
+
+            let count = walk_dfs(next, to, via, devices, &new_pass, mem)?;
+
+            mem.insert((next, new_pass), count);
+
+            Ok(count)
+        })
+        .sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    static TEST_DATA_1: &str = "aaa: you hhh
+you: bbb ccc
+bbb: ddd eee
+ccc: ddd eee fff
+ddd: ggg
+eee: out
+fff: out
+ggg: out
+hhh: ccc fff iii
+iii: out";
+
+    static TEST_DATA_2: &str = "svr: aaa bbb
+aaa: fft
+fft: ccc
+bbb: tty
+tty: ccc
+ccc: ddd eee
+ddd: hub
+hub: fff
+eee: dac
+dac: fff
+fff: ggg hhh
+ggg: out
+hhh: out";
+
+    #[test]
+    fn test_example_part_1() {
+        let devices = parse_v2(TEST_DATA_1).expect("Invalid devices");
+        let count = find_path_v2("you", "out", &[], &devices);
+        assert_eq!(count, Ok(5));
+    }
+
+    #[test]
+    fn test_example_part_2() {
+        let devices = parse_v2(TEST_DATA_2).expect("Invalid devices");
+        let count = find_path_v2("svr", "out", &["fft", "dac"], &devices);
+        assert_eq!(count, Ok(2));
+    }
+
+    #[test]
+    fn test_missing_device() {
+        let devices = parse_v2("aaa: bbb").expect("Invalid devices");
+        assert_eq!(
+            Day11::part1(&devices).map_err(|e| e.reason),
+            Err("Device `you` not found".to_string())
+        );
+
+        let devices = parse_v2("svr: aaa\naaa: out").expect("Invalid devices");
+        assert_eq!(Day11::part2(&devices), Ok(0));
+
+        let devices = parse_v2("").expect("Invalid devices");
+        assert_eq!(
+            Day11::part2(&devices).map_err(|e| e.reason),
+            Err("Device `svr` not found".to_string())
+        );
+    }
+
+    #[test]
+    fn test_parse_missing_separator() {
+        assert_eq!(
+            parse_v2("aaa: you\nyou bbb"),
+            Err(ParseError::missing(8, "you bbb", "':'").at_line(2))
+        );
+    }
+}
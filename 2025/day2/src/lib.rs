@@ -0,0 +1,203 @@
+mod repetition;
+
+pub use repetition::{Repetition, is_repetition_of, sum_repeated};
+
+use aoc_runner::{ParseError, Solution, SolveError, numbered_lines, parse_number};
+#[cfg(test)]
+use rayon::prelude::*;
+use std::ops::RangeInclusive;
+
+pub struct Day2;
+
+impl Solution for Day2 {
+    const DAY: u8 = 2;
+
+    type Input<'a> = Vec<RangeInclusive<i64>>;
+    type Part1 = i128;
+    type Part2 = i128;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        let mut ranges = Vec::new();
+
+        for (line, text) in numbered_lines(input) {
+            let mut offset = 0;
+            for range in text.split(',') {
+                ranges.push(parse(range).map_err(|e| e.shifted(offset).at_line(line))?);
+                offset += range.len() + 1;
+            }
+        }
+
+        Ok(ranges)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Result<Self::Part1, SolveError> {
+        Ok(input
+            .iter()
+            .map(|range| sum_repeated(range, Repetition::Twice))
+            .sum())
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Result<Self::Part2, SolveError> {
+        Ok(input
+            .iter()
+            .map(|range| sum_repeated(range, Repetition::AtLeastTwice))
+            .sum())
+    }
+}
+
+#[cfg(test)]
+fn compute(ranges: &[RangeInclusive<i64>], validator: fn(&i64) -> bool) -> i64 {
+    ranges
+        .par_iter()
+        .flat_map(|range| range.clone().into_par_iter())
+        .filter(|id| !validator(id))
+        .sum()
+}
+
+fn parse(range: &str) -> Result<RangeInclusive<i64>, ParseError> {
+    let (n1, n2) = range
+        .split_once("-")
+        .ok_or(ParseError::missing(1, range, "'-'"))?;
+    Ok(RangeInclusive::new(
+        parse_number(n1.trim(), 1)?,
+        parse_number(n2.trim(), n1.len() + 2)?,
+    ))
+}
+
+#[cfg(test)]
+fn is_valid_part_1(id: &i64) -> bool {
+    !is_repetition_of(*id, 2, 2)
+}
+
+#[cfg(test)]
+fn is_valid_part_2(id: &i64) -> bool {
+    !is_repetition_of(*id, 2, u32::MAX)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    fn is_valid_part_1_text(id: &i64) -> bool {
+        let text = id.to_string();
+        if !text.len().is_multiple_of(2) {
+            return true;
+        }
+
+        let (start, end) = text.split_at(text.len() / 2);
+        start != end
+    }
+
+    fn is_valid_part_2_text(id: &i64) -> bool {
+        let text = id.to_string();
+
+        let chars = text.chars();
+        let mut part = String::new();
+
+        for char in chars {
+            part.push(char);
+
+            if part == text {
+                break;
+            }
+
+            let size = text.len() / part.len();
+
+            if part.repeat(size) == text {
+                return false;
+            }
+        }
+
+        true
+    }
+
+    proptest! {
+        #[test]
+        fn test_numeric_matches_text(id in any::<i64>()) {
+            prop_assert_eq!(is_valid_part_1(&id), is_valid_part_1_text(&id));
+            prop_assert_eq!(is_valid_part_2(&id), is_valid_part_2_text(&id));
+        }
+
+        #[test]
+        fn test_numeric_matches_text_on_repetitions(block in 1i64..100_000, repeats in 1usize..5) {
+            let text = block.to_string().repeat(repeats);
+            prop_assume!(text.len() <= 18);
+            let id: i64 = text.parse().unwrap();
+
+            prop_assert_eq!(is_valid_part_1(&id), is_valid_part_1_text(&id));
+            prop_assert_eq!(is_valid_part_2(&id), is_valid_part_2_text(&id));
+        }
+    }
+
+    #[test]
+    fn test_parse() {
+        assert_eq!(parse("11-22"), Ok(RangeInclusive::new(11, 22)));
+        assert_eq!(
+            parse("222220-222224"),
+            Ok(RangeInclusive::new(222220, 222224))
+        );
+        assert_eq!(
+            Day2::parse("11-22,95-1x5"),
+            Err(ParseError::invalid_number(10, "1x5"))
+        );
+        assert_eq!(
+            Day2::parse("11-22,95"),
+            Err(ParseError::missing(7, "95", "'-'"))
+        );
+    }
+
+    #[test]
+    fn test_valid_part_1() {
+        assert!(!is_valid_part_1(&11));
+        assert!(is_valid_part_1(&12));
+        assert!(is_valid_part_1(&222221));
+    }
+
+    #[test]
+    fn test_valid_part_2() {
+        assert!(!is_valid_part_2(&11));
+        assert!(is_valid_part_2(&12));
+        assert!(!is_valid_part_2(&111));
+        assert!(!is_valid_part_2(&212121));
+    }
+
+    #[test]
+    fn test_with_example_part_1() {
+        let test_data = "11-22,95-115,998-1012,1188511880-1188511890,222220-222224,1698522-1698528,446443-446449,38593856-38593862,565653-565659,824824821-824824827,2121212118-2121212124";
+
+        let result: i64 = compute(
+            &Day2::parse(test_data).expect("Invalid ranges"),
+            is_valid_part_1,
+        );
+
+        assert_eq!(result, 1227775554);
+    }
+
+    #[test]
+    fn test_with_example_part_2() {
+        let test_data = "11-22,95-115,998-1012,1188511880-1188511890,222220-222224,1698522-1698528,446443-446449,38593856-38593862,565653-565659,824824821-824824827,2121212118-2121212124";
+
+        let result: i64 = compute(
+            &Day2::parse(test_data).expect("Invalid ranges"),
+            is_valid_part_2,
+        );
+
+        assert_eq!(result, 4174379265);
+    }
+
+    #[test]
+    fn test_arithmetic_matches_enumeration() {
+        let test_data = "11-22,95-115,998-1012,1188511880-1188511890,222220-222224,1698522-1698528,446443-446449,38593856-38593862,565653-565659,824824821-824824827,2121212118-2121212124,1-200000,999990-1010102";
+        let ranges = Day2::parse(test_data).expect("Invalid ranges");
+
+        assert_eq!(
+            Day2::part1(&ranges),
+            Ok(compute(&ranges, is_valid_part_1) as i128)
+        );
+        assert_eq!(
+            Day2::part2(&ranges),
+            Ok(compute(&ranges, is_valid_part_2) as i128)
+        );
+    }
+}
@@ -0,0 +1,143 @@
+use std::ops::RangeInclusive;
+
+#[derive(Eq, PartialEq, Copy, Clone, Debug)]
+pub enum Repetition {
+    /// A block of digits written exactly twice, like `123123`.
+    Twice,
+    /// A block of digits written at least twice, like `121212`.
+    AtLeastTwice,
+}
+
+/// Sum the IDs of the range made of a repeated block of digits, without enumerating them.
+///
+/// For `d` digits and a block of `p` digits, the repeated IDs are `block * (10^d - 1) / (10^p - 1)`
+/// so their sum in a range is a multiple of an arithmetic series over the blocks. An ID made
+/// of a block of `q` digits is also made of a block of `p` digits for every `q | p`, the sums
+/// are therefore reduced to the smallest block by inclusion–exclusion over the divisors of `d`.
+pub fn sum_repeated(range: &RangeInclusive<i64>, repetition: Repetition) -> i128 {
+    let (low, high) = (*range.start() as i128, *range.end() as i128);
+    if high < 1 || low > high {
+        return 0;
+    }
+
+    (1..=digits(high))
+        .map(|size| sum_with_digits(low, high, size, repetition))
+        .sum()
+}
+
+/// Whether the ID is a block of digits written between `min_repeats` and `max_repeats` times.
+///
+/// An ID of `d` digits made of a block repeated `k` times is a multiple of
+/// `(10^d - 1) / (10^(d/k) - 1)`, like `1001` for `123123` or `10101` for `121212`,
+/// and the quotient is the block itself.
+pub fn is_repetition_of(n: i64, min_repeats: u32, max_repeats: u32) -> bool {
+    if n < 1 {
+        return false;
+    }
+
+    let number = n as i128;
+    let size = digits(number);
+    (min_repeats.max(1)..=max_repeats.min(size))
+        .filter(|repeats| size.is_multiple_of(*repeats))
+        .any(|repeats| number % multiplier(size, size / repeats) == 0)
+}
+
+fn digits(number: i128) -> u32 {
+    number.ilog10() + 1
+}
+
+fn sum_with_digits(low: i128, high: i128, size: u32, repetition: Repetition) -> i128 {
+    let low = low.max(10i128.pow(size - 1));
+    let high = high.min(10i128.pow(size) - 1);
+    if low > high {
+        return 0;
+    }
+
+    match repetition {
+        Repetition::Twice if size.is_multiple_of(2) => sum_with_block(low, high, size, size / 2),
+        Repetition::Twice => 0,
+        Repetition::AtLeastTwice => {
+            let blocks: Vec<u32> = (1..size).filter(|p| size.is_multiple_of(*p)).collect();
+
+            let mut primitive: Vec<(u32, i128)> = Vec::new();
+            for block in blocks {
+                let shorter: i128 = primitive
+                    .iter()
+                    .filter(|(smaller, _)| block.is_multiple_of(*smaller))
+                    .map(|(_, sum)| sum)
+                    .sum();
+                primitive.push((block, sum_with_block(low, high, size, block) - shorter));
+            }
+
+            primitive.iter().map(|(_, sum)| sum).sum()
+        }
+    }
+}
+
+fn multiplier(size: u32, block: u32) -> i128 {
+    (10i128.pow(size) - 1) / (10i128.pow(block) - 1)
+}
+
+fn sum_with_block(low: i128, high: i128, size: u32, block: u32) -> i128 {
+    let multiplier = multiplier(size, block);
+
+    let first = 10i128
+        .pow(block - 1)
+        .max((low + multiplier - 1) / multiplier);
+    let last = (10i128.pow(block) - 1).min(high / multiplier);
+    if first > last {
+        return 0;
+    }
+
+    multiplier * (first + last) * (last - first + 1) / 2
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sum_repeated() {
+        assert_eq!(sum_repeated(&(11..=22), Repetition::Twice), 33);
+        assert_eq!(sum_repeated(&(95..=115), Repetition::Twice), 99);
+        assert_eq!(sum_repeated(&(95..=115), Repetition::AtLeastTwice), 210);
+        assert_eq!(sum_repeated(&(998..=1012), Repetition::AtLeastTwice), 2009);
+        assert_eq!(
+            sum_repeated(&(222220..=222224), Repetition::AtLeastTwice),
+            222222
+        );
+    }
+
+    #[test]
+    fn test_sum_repeated_counts_once() {
+        assert_eq!(sum_repeated(&(1111..=1111), Repetition::Twice), 1111);
+        assert_eq!(sum_repeated(&(1111..=1111), Repetition::AtLeastTwice), 1111);
+        assert_eq!(
+            sum_repeated(&(111111..=111111), Repetition::AtLeastTwice),
+            111111
+        );
+    }
+
+    #[test]
+    fn test_is_repetition_of() {
+        assert!(is_repetition_of(123123, 2, 2));
+        assert!(!is_repetition_of(121212, 2, 2));
+        assert!(is_repetition_of(121212, 2, 3));
+        assert!(is_repetition_of(1111111, 2, u32::MAX));
+        assert!(!is_repetition_of(1111111, 2, 6));
+        assert!(is_repetition_of(12, 1, 1));
+        assert!(!is_repetition_of(7, 2, u32::MAX));
+        assert!(!is_repetition_of(0, 1, u32::MAX));
+        assert!(!is_repetition_of(-11, 2, 2));
+        assert!(is_repetition_of(123_456_789_123_456_789, 2, 2));
+    }
+
+    #[test]
+    fn test_sum_repeated_huge_range() {
+        assert_eq!(
+            sum_repeated(&(1..=i64::MAX), Repetition::Twice),
+            sum_repeated(&(1..=999_999_999_999_999_999), Repetition::Twice)
+        );
+        assert!(sum_repeated(&(1..=i64::MAX), Repetition::AtLeastTwice) > i64::MAX as i128);
+    }
+}
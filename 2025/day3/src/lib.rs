@@ -0,0 +1,135 @@
+mod selection;
+
+pub use selection::{BankError, extract, largest_subsequence};
+
+use aoc_runner::{ParseError, Solution, SolveError, indentation, numbered_lines};
+use rayon::prelude::*;
+
+pub struct Day3;
+
+const LARGEST_SELECTION: usize = 12;
+
+impl Solution for Day3 {
+    const DAY: u8 = 3;
+
+    type Input<'a> = Vec<&'a str>;
+    type Part1 = u128;
+    type Part2 = u128;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        numbered_lines(input)
+            .map(|(line, raw)| {
+                let batteries = raw.trim();
+                match batteries.char_indices().find(|(_, c)| !c.is_ascii_digit()) {
+                    Some((index, c)) => Err(ParseError::unexpected(
+                        index + indentation(raw),
+                        &c.to_string(),
+                        "a battery joltage digit",
+                    )
+                    .at_line(line)),
+                    None => Ok(batteries),
+                }
+            })
+            .collect()
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Result<Self::Part1, SolveError> {
+        total(input, 2)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Result<Self::Part2, SolveError> {
+        total(input, LARGEST_SELECTION)
+    }
+}
+
+/// Sum of the largest joltage of every bank.
+fn total(banks: &[&str], size: usize) -> Result<u128, SolveError> {
+    banks
+        .par_iter()
+        .enumerate()
+        .map(|(index, batteries)| {
+            extract(batteries, size)
+                .map_err(|e| SolveError::new(format!("Bank {}: {e}", index + 1)))
+        })
+        .sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_extract_with_size_2() {
+        assert_eq!(extract("987654321111111", 2), Ok(98));
+        assert_eq!(extract("811111111111119", 2), Ok(89));
+        assert_eq!(extract("234234234234278", 2), Ok(78));
+        assert_eq!(extract("818181911112111", 2), Ok(92));
+        assert_eq!(
+            extract(
+                "2344323254238324344443324333412234342243363246314375326354514244431354834344246137562233387223242853",
+                2
+            ),
+            Ok(88)
+        );
+    }
+
+    #[test]
+    fn test_extract_with_size_12() {
+        assert_eq!(extract("987654321111111", 12), Ok(987654321111));
+        assert_eq!(extract("811111111111119", 12), Ok(811111111119));
+        assert_eq!(extract("234234234234278", 12), Ok(434234234278));
+        assert_eq!(extract("818181911112111", 12), Ok(888911112111));
+        assert_eq!(
+            extract(
+                "2344323254238324344443324333412234342243363246314375326354514244431354834344246137562233387223242853",
+                12
+            ),
+            Ok(888723242853)
+        );
+    }
+
+    #[test]
+    fn test_with_example_part_1() {
+        let test_data = "987654321111111
+811111111111119
+234234234234278
+818181911112111";
+
+        let banks = Day3::parse(test_data).expect("Invalid banks");
+
+        assert_eq!(Day3::part1(&banks), Ok(357));
+    }
+
+    #[test]
+    fn test_with_example_part_2() {
+        let test_data = "987654321111111
+811111111111119
+234234234234278
+818181911112111";
+
+        let banks = Day3::parse(test_data).expect("Invalid banks");
+
+        assert_eq!(Day3::part2(&banks), Ok(3121910778619));
+    }
+
+    #[test]
+    fn test_parse_invalid_battery() {
+        assert_eq!(
+            Day3::parse("987654321111111\n81111a111111119"),
+            Err(ParseError::unexpected(6, "a", "a battery joltage digit").at_line(2))
+        );
+    }
+
+    #[test]
+    fn test_short_bank() {
+        let banks = Day3::parse("987654321111111\n  12345").expect("Invalid banks");
+
+        assert_eq!(Day3::part1(&banks), Ok(98 + 45));
+        assert_eq!(
+            Day3::part2(&banks),
+            Err(SolveError::new(
+                "Bank 2: Cannot turn on 12 batteries in a bank of 5"
+            ))
+        );
+    }
+}
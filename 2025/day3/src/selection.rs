@@ -0,0 +1,142 @@
+use std::fmt::{Display, Formatter};
+
+#[derive(Debug, Eq, PartialEq, Copy, Clone)]
+pub enum BankError {
+    TooShort {
+        wanted: usize,
+        available: usize,
+    },
+    Overflow {
+        size: usize,
+    },
+    /// A character which is not a digit, at a position starting at 1.
+    InvalidDigit {
+        position: usize,
+        found: char,
+    },
+}
+
+impl Display for BankError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            BankError::TooShort { wanted, available } => write!(
+                f,
+                "Cannot turn on {wanted} batteries in a bank of {available}"
+            ),
+            BankError::Overflow { size } => {
+                write!(f, "A joltage of {size} digits does not fit in 128 bits")
+            }
+            BankError::InvalidDigit { position, found } => {
+                write!(f, "Battery {position} has no joltage digit but [{found}]")
+            }
+        }
+    }
+}
+
+impl std::error::Error for BankError {}
+
+/// Largest subsequence of `size` items, compared lexicographically.
+///
+/// Items are pushed on a stack in one pass, popping every smaller item before them
+/// as long as enough items remain to fill the selection.
+pub fn largest_subsequence<T: Ord + Copy>(items: &[T], size: usize) -> Result<Vec<T>, BankError> {
+    let Some(mut drops) = items.len().checked_sub(size) else {
+        return Err(BankError::TooShort {
+            wanted: size,
+            available: items.len(),
+        });
+    };
+
+    let mut stack: Vec<T> = Vec::with_capacity(items.len());
+    for item in items {
+        while drops > 0 && stack.last().is_some_and(|top| top < item) {
+            stack.pop();
+            drops -= 1;
+        }
+        stack.push(*item);
+    }
+
+    stack.truncate(size);
+    Ok(stack)
+}
+
+/// Largest joltage made of `size` batteries of the bank, kept in order.
+pub fn extract(batteries: &str, size: usize) -> Result<u128, BankError> {
+    if let Some((position, found)) = batteries
+        .chars()
+        .enumerate()
+        .find(|(_, c)| !c.is_ascii_digit())
+    {
+        return Err(BankError::InvalidDigit {
+            position: position + 1,
+            found,
+        });
+    }
+
+    largest_subsequence(batteries.as_bytes(), size)?
+        .iter()
+        .try_fold(0u128, |joltage, digit| {
+            joltage
+                .checked_mul(10)?
+                .checked_add(u128::from(digit - b'0'))
+        })
+        .ok_or(BankError::Overflow { size })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_largest_subsequence() {
+        assert_eq!(
+            largest_subsequence(&[3, 1, 4, 1, 5, 9, 2, 6], 3),
+            Ok(vec![9, 2, 6])
+        );
+        assert_eq!(
+            largest_subsequence(&[3, 1, 4, 1, 5, 9, 2, 6], 8).map(|s| s.len()),
+            Ok(8)
+        );
+        assert_eq!(largest_subsequence(&['b', 'a', 'c'], 0), Ok(vec![]));
+        assert_eq!(
+            largest_subsequence(&[1, 2], 3),
+            Err(BankError::TooShort {
+                wanted: 3,
+                available: 2
+            })
+        );
+    }
+
+    #[test]
+    fn test_extract_invalid_digit() {
+        assert_eq!(
+            extract("12a4", 2),
+            Err(BankError::InvalidDigit {
+                position: 3,
+                found: 'a'
+            })
+        );
+        assert_eq!(
+            extract("1/2", 2),
+            Err(BankError::InvalidDigit {
+                position: 2,
+                found: '/'
+            })
+        );
+    }
+
+    #[test]
+    fn test_extract_large_selection() {
+        let bank = "9".repeat(50);
+
+        assert_eq!(extract(&bank, 38), Ok(10u128.pow(38) - 1));
+        assert_eq!(extract(&bank, 40), Err(BankError::Overflow { size: 40 }));
+        assert_eq!(
+            extract("12", 12),
+            Err(BankError::TooShort {
+                wanted: 12,
+                available: 2
+            })
+        );
+    }
+}
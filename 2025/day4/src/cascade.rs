@@ -0,0 +1,161 @@
+use crate::{Rule, get_movable, remove_moved};
+use grid::{Coordinate, Grid};
+use std::fmt::{Display, Formatter};
+
+const REMOVED: &str = "\x1b[1;31m";
+const DIMMED: &str = "\x1b[2m";
+const RESET: &str = "\x1b[0m";
+
+/// The shelf after a removal round, the first frame being the untouched shelf.
+#[derive(Debug, Eq, PartialEq, Clone)]
+pub struct Frame {
+    pub round: usize,
+    pub removed: usize,
+    shelf: Grid<bool>,
+    removed_at: Grid<Option<usize>>,
+}
+
+/// Frames of the removal rounds until the shelf is stable.
+pub struct Cascade<'a> {
+    rule: &'a Rule,
+    next: Option<Frame>,
+}
+
+impl<'a> Cascade<'a> {
+    pub fn new(shelf: &Grid<bool>, rule: &'a Rule) -> Self {
+        Cascade {
+            rule,
+            next: Some(Frame {
+                round: 0,
+                removed: 0,
+                shelf: shelf.clone(),
+                removed_at: shelf.map(|_| None),
+            }),
+        }
+    }
+}
+
+impl Iterator for Cascade<'_> {
+    type Item = Frame;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let frame = self.next.take()?;
+
+        let movable = get_movable(&frame.shelf, self.rule);
+        if !movable.is_empty() {
+            let round = frame.round + 1;
+            let mut removed_at = frame.removed_at.clone();
+            movable
+                .iter()
+                .for_each(|position| removed_at[*position] = Some(round));
+
+            self.next = Some(Frame {
+                round,
+                removed: movable.len(),
+                shelf: remove_moved(&frame.shelf, &movable),
+                removed_at,
+            });
+        }
+
+        Some(frame)
+    }
+}
+
+impl Frame {
+    /// The frame with ANSI colours, rolls removed this round are highlighted and older ones dimmed.
+    pub fn coloured(&self) -> String {
+        self.render(true)
+    }
+
+    fn render(&self, colours: bool) -> String {
+        let mut text = format!("Round {}: {} rolls removed", self.round, self.removed);
+
+        for row in 0..self.shelf.height() {
+            text.push('\n');
+            for column in 0..self.shelf.width() {
+                let position = Coordinate::new(row, column);
+                let (style, symbol) = match (self.shelf[position], self.removed_at[position]) {
+                    (true, _) => (None, '@'),
+                    (false, Some(round)) if round == self.round => (Some(REMOVED), 'x'),
+                    (false, Some(_)) => (Some(DIMMED), '-'),
+                    (false, None) => (None, '.'),
+                };
+
+                match style {
+                    Some(style) if colours => text.push_str(&format!("{style}{symbol}{RESET}")),
+                    _ => text.push(symbol),
+                }
+            }
+        }
+
+        text
+    }
+}
+
+impl Display for Frame {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.render(false))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse;
+
+    #[test]
+    fn test_frames() {
+        let shelf = parse(".@.\n@@@\n.@.").expect("Invalid shelf");
+        let rule = Rule::default();
+
+        let frames: Vec<String> = Cascade::new(&shelf, &rule)
+            .map(|frame| frame.to_string())
+            .collect();
+
+        assert_eq!(
+            frames,
+            vec![
+                "Round 0: 0 rolls removed\n.@.\n@@@\n.@.",
+                "Round 1: 4 rolls removed\n.x.\nx@x\n.x.",
+                "Round 2: 1 rolls removed\n.-.\n-x-\n.-.",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_coloured() {
+        let shelf = parse(".@.\n@@@\n.@.").expect("Invalid shelf");
+        let rule = Rule::default();
+
+        let last = Cascade::new(&shelf, &rule).last().expect("No frame");
+
+        assert!(last.coloured().ends_with(&format!(
+            "\n.{DIMMED}-{RESET}.\n{DIMMED}-{RESET}{REMOVED}x{RESET}{DIMMED}-{RESET}\n.{DIMMED}-{RESET}."
+        )));
+    }
+
+    #[test]
+    fn test_removed_rounds() {
+        let shelf = parse(
+            "..@@.@@@@.
+@@@.@.@.@@
+@@@@@.@.@@
+@.@@@@..@.
+@@.@@@@.@@
+.@@@@@@@.@
+.@.@.@.@@@
+@.@@@.@@@@
+.@@@@@@@@.
+@.@.@@@.@.",
+        )
+        .expect("Invalid shelf");
+        let rule = Rule::default();
+
+        let removed: Vec<usize> = Cascade::new(&shelf, &rule)
+            .skip(1)
+            .map(|frame| frame.removed)
+            .collect();
+
+        assert_eq!(removed, crate::remove_all(&shelf, &rule).rounds);
+    }
+}
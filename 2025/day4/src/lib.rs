@@ -0,0 +1,164 @@
+mod cascade;
+mod removal;
+mod rule;
+
+pub use cascade::{Cascade, Frame};
+pub use removal::{Removal, remove_all};
+pub use rule::{Edges, Neighbourhood, Rule};
+
+use aoc_runner::{ParseError, Solution, SolveError};
+use grid::{Coordinate, Grid};
+
+pub struct Day4;
+
+impl Solution for Day4 {
+    const DAY: u8 = 4;
+
+    type Input<'a> = Grid<bool>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Result<Self::Part1, SolveError> {
+        Ok(get_movable(input, &Rule::default()).len())
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Result<Self::Part2, SolveError> {
+        Ok(remove_all(input, &Rule::default()).total())
+    }
+}
+
+fn parse(input: &str) -> Result<Grid<bool>, ParseError> {
+    Grid::parse(
+        input,
+        |c| match c {
+            '.' => Some(false),
+            '@' => Some(true),
+            _ => None,
+        },
+        "'.' or '@'",
+    )
+}
+
+fn get_movable(shelf: &Grid<bool>, rule: &Rule) -> Vec<Coordinate> {
+    shelf
+        .coordinates()
+        .filter(|position| {
+            count_adjacent(shelf, *position, rule).is_some_and(|count| rule.is_accessible(count))
+        })
+        .collect()
+}
+
+fn remove_moved(shelf: &Grid<bool>, movable: &[Coordinate]) -> Grid<bool> {
+    let mut new_shelf = shelf.clone();
+    movable
+        .iter()
+        .for_each(|position| new_shelf[*position] = false);
+    new_shelf
+}
+
+/// Number of rolls around the roll at `pos`, `None` when there is no roll.
+fn count_adjacent(shelf: &Grid<bool>, pos: Coordinate, rule: &Rule) -> Option<usize> {
+    if !shelf.get(pos).copied().unwrap_or(false) {
+        return None;
+    }
+
+    Some(
+        rule.neighbours(shelf, pos)
+            .into_iter()
+            .filter(|neighbour| shelf[*neighbour])
+            .count(),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parsing_basic() {
+        let test_data = "@.
+                               @.";
+
+        let expected = Grid::from_cells(2, vec![true, false, true, false]);
+
+        assert_eq!(parse(test_data), Ok(expected));
+        assert_eq!(
+            parse("@.\n.x"),
+            Err(ParseError::unexpected(2, "x", "'.' or '@'").at_line(2))
+        );
+    }
+
+    #[test]
+    fn test_count_adjacent() {
+        let test_data = Grid::from_cells(2, vec![true, false, true, false]);
+        let rule = Rule::default();
+
+        assert_eq!(
+            count_adjacent(&test_data, Coordinate::new(0, 0), &rule),
+            Some(1)
+        );
+        assert_eq!(
+            count_adjacent(&test_data, Coordinate::new(0, 1), &rule),
+            None
+        );
+        assert_eq!(
+            count_adjacent(&test_data, Coordinate::new(1, 0), &rule),
+            Some(1)
+        );
+        assert_eq!(
+            count_adjacent(&test_data, Coordinate::new(1, 1), &rule),
+            None
+        );
+    }
+
+    #[test]
+    fn test_with_example_part_1() {
+        let test_data = "..@@.@@@@.
+@@@.@.@.@@
+@@@@@.@.@@
+@.@@@@..@.
+@@.@@@@.@@
+.@@@@@@@.@
+.@.@.@.@@@
+@.@@@.@@@@
+.@@@@@@@@.
+@.@.@@@.@.";
+
+        let result = get_movable(&parse(test_data).expect("Invalid shelf"), &Rule::default()).len();
+
+        assert_eq!(result, 13);
+    }
+
+    #[test]
+    fn test_with_example_part_2() {
+        let test_data = "..@@.@@@@.
+@@@.@.@.@@
+@@@@@.@.@@
+@.@@@@..@.
+@@.@@@@.@@
+.@@@@@@@.@
+.@.@.@.@@@
+@.@@@.@@@@
+.@@@@@@@@.
+@.@.@@@.@.";
+
+        let mut input = parse(test_data).expect("Invalid shelf");
+        let mut movable = get_movable(&input, &Rule::default());
+
+        println!("Sum for part 1 is {}", movable.len());
+
+        let mut result = movable.len();
+
+        while !movable.is_empty() {
+            input = remove_moved(&input, &movable);
+            movable = get_movable(&input, &Rule::default());
+            result += movable.len();
+        }
+
+        assert_eq!(result, 43);
+    }
+}
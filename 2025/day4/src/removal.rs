@@ -0,0 +1,151 @@
+use crate::{Rule, count_adjacent};
+use grid::{Coordinate, Grid};
+
+#[derive(Debug, Eq, PartialEq, Clone)]
+pub struct Removal {
+    /// Number of rolls removed at each round.
+    pub rounds: Vec<usize>,
+    /// The shelf once no roll can be removed.
+    pub shelf: Grid<bool>,
+}
+
+impl Removal {
+    pub fn total(&self) -> usize {
+        self.rounds.iter().sum()
+    }
+}
+
+/// Remove the accessible rolls round after round until the shelf is stable.
+///
+/// The number of neighbouring rolls is kept for every roll and only decreases, so a roll
+/// becomes accessible exactly when its count crosses the threshold while its neighbours
+/// are removed and is queued for the next round at that moment.
+pub fn remove_all(shelf: &Grid<bool>, rule: &Rule) -> Removal {
+    let mut shelf = shelf.clone();
+    let mut neighbours = Grid::from_cells(
+        shelf.width(),
+        shelf
+            .coordinates()
+            .map(|position| count_adjacent(&shelf, position, rule))
+            .collect(),
+    );
+
+    let mut queue: Vec<Coordinate> = shelf
+        .coordinates()
+        .filter(|position| neighbours[*position].is_some_and(|count| rule.is_accessible(count)))
+        .collect();
+    let mut rounds = Vec::new();
+
+    while !queue.is_empty() {
+        rounds.push(queue.len());
+        queue.iter().for_each(|position| {
+            shelf[*position] = false;
+            neighbours[*position] = None;
+        });
+
+        let mut next = Vec::new();
+        for position in &queue {
+            for dependant in rule.dependants(&shelf, *position) {
+                if let Some(count) = &mut neighbours[dependant] {
+                    *count -= 1;
+                    if rule.is_accessible(*count) && !rule.is_accessible(*count + 1) {
+                        next.push(dependant);
+                    }
+                }
+            }
+        }
+        queue = next;
+    }
+
+    Removal { rounds, shelf }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Edges, Neighbourhood, get_movable, parse, remove_moved};
+
+    const TEST_DATA: &str = "..@@.@@@@.
+@@@.@.@.@@
+@@@@@.@.@@
+@.@@@@..@.
+@@.@@@@.@@
+.@@@@@@@.@
+.@.@.@.@@@
+@.@@@.@@@@
+.@@@@@@@@.
+@.@.@@@.@.";
+
+    #[test]
+    fn test_remove_all() {
+        let removal = remove_all(&parse(TEST_DATA).expect("Invalid shelf"), &Rule::default());
+
+        assert_eq!(removal.rounds, vec![13, 12, 7, 5, 2, 1, 1, 1, 1]);
+        assert_eq!(removal.total(), 43);
+        assert_eq!(
+            removal
+                .shelf
+                .map(|roll| if *roll { '@' } else { '.' })
+                .to_string(),
+            "..........
+..........
+..........
+....@@....
+...@@@@...
+...@@@@@..
+...@.@.@@.
+...@@.@@@.
+...@@@@@..
+....@@@..."
+        );
+    }
+
+    fn full_rescan(rule: &Rule) -> Removal {
+        let mut shelf = parse(TEST_DATA).expect("Invalid shelf");
+        let mut rounds = Vec::new();
+        let mut movable = get_movable(&shelf, rule);
+
+        while !movable.is_empty() {
+            rounds.push(movable.len());
+            shelf = remove_moved(&shelf, &movable);
+            movable = get_movable(&shelf, rule);
+        }
+
+        Removal { rounds, shelf }
+    }
+
+    #[test]
+    fn test_matches_full_rescan() {
+        let rules = [
+            Rule::default(),
+            Rule {
+                neighbourhood: Neighbourhood::VonNeumann,
+                threshold: 3,
+                edges: Edges::Bounded,
+            },
+            Rule {
+                neighbourhood: Neighbourhood::Moore,
+                threshold: 5,
+                edges: Edges::Wrapping,
+            },
+            Rule {
+                neighbourhood: Neighbourhood::Custom(vec![
+                    (0, -2),
+                    (0, 2),
+                    (-2, 0),
+                    (2, 0),
+                    (1, 1),
+                ]),
+                threshold: 2,
+                edges: Edges::Wrapping,
+            },
+        ];
+
+        for rule in rules {
+            assert_eq!(
+                remove_all(&parse(TEST_DATA).expect("Invalid shelf"), &rule),
+                full_rescan(&rule)
+            );
+        }
+    }
+}
@@ -0,0 +1,117 @@
+use grid::{ADJACENT, Coordinate, Grid, ORTHOGONAL};
+
+/// Cells looked at around a roll.
+#[derive(Debug, Eq, PartialEq, Clone)]
+pub enum Neighbourhood {
+    /// The four cells sharing a side.
+    VonNeumann,
+    /// The eight cells sharing a side or a corner.
+    Moore,
+    /// Any `(rows, columns)` offsets.
+    Custom(Vec<(isize, isize)>),
+}
+
+#[derive(Debug, Eq, PartialEq, Copy, Clone)]
+pub enum Edges {
+    /// Nothing lies beyond the shelf.
+    Bounded,
+    /// Leaving one side of the shelf enters from the opposite one.
+    Wrapping,
+}
+
+/// When a roll can be reached by a forklift.
+#[derive(Debug, Eq, PartialEq, Clone)]
+pub struct Rule {
+    pub neighbourhood: Neighbourhood,
+    /// A roll is accessible with fewer neighbouring rolls than this.
+    pub threshold: usize,
+    pub edges: Edges,
+}
+
+impl Default for Rule {
+    /// The rule of the puzzle.
+    fn default() -> Self {
+        Rule {
+            neighbourhood: Neighbourhood::Moore,
+            threshold: 4,
+            edges: Edges::Bounded,
+        }
+    }
+}
+
+impl Rule {
+    fn offsets(&self) -> &[(isize, isize)] {
+        match &self.neighbourhood {
+            Neighbourhood::VonNeumann => &ORTHOGONAL,
+            Neighbourhood::Moore => &ADJACENT,
+            Neighbourhood::Custom(offsets) => offsets,
+        }
+    }
+
+    pub fn neighbours<T>(&self, shelf: &Grid<T>, position: Coordinate) -> Vec<Coordinate> {
+        self.cells(shelf, position, self.offsets())
+    }
+
+    /// Cells having `position` among their neighbours, which differ from its neighbours
+    /// when the offsets are not symmetric.
+    pub fn dependants<T>(&self, shelf: &Grid<T>, position: Coordinate) -> Vec<Coordinate> {
+        let mirrored: Vec<(isize, isize)> = self
+            .offsets()
+            .iter()
+            .map(|(rows, columns)| (-rows, -columns))
+            .collect();
+        self.cells(shelf, position, &mirrored)
+    }
+
+    fn cells<T>(
+        &self,
+        shelf: &Grid<T>,
+        position: Coordinate,
+        offsets: &[(isize, isize)],
+    ) -> Vec<Coordinate> {
+        match self.edges {
+            Edges::Bounded => shelf.neighbours(position, offsets).collect(),
+            Edges::Wrapping => shelf.wrapping_neighbours(position, offsets).collect(),
+        }
+    }
+
+    pub fn is_accessible(&self, neighbouring_rolls: usize) -> bool {
+        neighbouring_rolls < self.threshold
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_neighbours() {
+        let shelf = Grid::new(3, 3, false);
+        let corner = Coordinate::new(0, 0);
+
+        assert_eq!(Rule::default().neighbours(&shelf, corner).len(), 3);
+
+        let rule = Rule {
+            neighbourhood: Neighbourhood::VonNeumann,
+            threshold: 1,
+            edges: Edges::Wrapping,
+        };
+        assert_eq!(
+            rule.neighbours(&shelf, corner),
+            vec![
+                Coordinate::new(2, 0),
+                Coordinate::new(0, 2),
+                Coordinate::new(0, 1),
+                Coordinate::new(1, 0)
+            ]
+        );
+
+        let rule = Rule {
+            neighbourhood: Neighbourhood::Custom(vec![(2, 2), (5, 0)]),
+            ..Rule::default()
+        };
+        assert_eq!(rule.neighbours(&shelf, corner), vec![Coordinate::new(2, 2)]);
+        assert_eq!(rule.dependants(&shelf, corner), vec![]);
+        assert_eq!(rule.dependants(&shelf, Coordinate::new(2, 2)), vec![corner]);
+    }
+}
@@ -0,0 +1,69 @@
+use criterion::{Criterion, criterion_group, criterion_main};
+use day5::IntervalSet;
+use rand::rngs::StdRng;
+use rand::{RngExt, SeedableRng};
+use std::hint::black_box;
+use std::ops::RangeInclusive;
+
+const INGREDIENTS: usize = 1_000_000;
+const RANGES: usize = 2_000;
+const MAX_ID: i64 = 500_000_000_000_000;
+
+fn generate() -> (IntervalSet<i64>, Vec<i64>) {
+    let mut rng = StdRng::seed_from_u64(5);
+
+    let fresh_ranges: IntervalSet<i64> = (0..RANGES)
+        .map(|_| {
+            let start = rng.random_range(0..MAX_ID);
+            start..=start + rng.random_range(0..MAX_ID / RANGES as i64)
+        })
+        .collect();
+    let ingredients = (0..INGREDIENTS)
+        .map(|_| rng.random_range(0..MAX_ID))
+        .collect();
+
+    (fresh_ranges, ingredients)
+}
+
+fn linear_scan(ranges: &[RangeInclusive<i64>], ingredients: &[i64]) -> usize {
+    ingredients
+        .iter()
+        .filter(|ingredient| ranges.iter().any(|range| range.contains(ingredient)))
+        .count()
+}
+
+fn binary_search(fresh_ranges: &IntervalSet<i64>, ingredients: &[i64]) -> usize {
+    ingredients
+        .iter()
+        .filter(|ingredient| fresh_ranges.contains(ingredient))
+        .count()
+}
+
+fn merge_join(fresh_ranges: &IntervalSet<i64>, ingredients: &[i64]) -> usize {
+    let mut sorted = ingredients.to_vec();
+    sorted.sort_unstable();
+    fresh_ranges.count_contained(&sorted)
+}
+
+fn fresh_ingredients(c: &mut Criterion) {
+    let (fresh_ranges, ingredients) = generate();
+
+    let expected = binary_search(&fresh_ranges, &ingredients);
+    assert_eq!(merge_join(&fresh_ranges, &ingredients), expected);
+
+    let mut group = c.benchmark_group("count_fresh_ingredients");
+    group.sample_size(10);
+    group.bench_function("linear_scan", |b| {
+        b.iter(|| linear_scan(black_box(fresh_ranges.ranges()), black_box(&ingredients)))
+    });
+    group.bench_function("binary_search", |b| {
+        b.iter(|| binary_search(black_box(&fresh_ranges), black_box(&ingredients)))
+    });
+    group.bench_function("merge_join", |b| {
+        b.iter(|| merge_join(black_box(&fresh_ranges), black_box(&ingredients)))
+    });
+    group.finish();
+}
+
+criterion_group!(benches, fresh_ingredients);
+criterion_main!(benches);
@@ -0,0 +1,268 @@
+use std::ops::RangeInclusive;
+
+/// Integer types whose values can be enumerated one after the other.
+pub trait Discrete: Copy + Ord {
+    const MIN: Self;
+    const MAX: Self;
+
+    fn successor(self) -> Self;
+
+    fn predecessor(self) -> Self;
+
+    /// Number of values in `start..=end`, saturating at `u64::MAX`.
+    fn count(start: Self, end: Self) -> u64;
+}
+
+macro_rules! discrete {
+    ($($integer:ty),*) => {
+        $(
+            impl Discrete for $integer {
+                const MIN: Self = <$integer>::MIN;
+                const MAX: Self = <$integer>::MAX;
+
+                fn successor(self) -> Self {
+                    self.saturating_add(1)
+                }
+
+                fn predecessor(self) -> Self {
+                    self.saturating_sub(1)
+                }
+
+                fn count(start: Self, end: Self) -> u64 {
+                    u64::try_from(end as i128 - start as i128 + 1).unwrap_or(u64::MAX)
+                }
+            }
+        )*
+    };
+}
+
+discrete!(i32, i64, u32, u64, usize);
+
+/// Sorted, disjoint and non adjacent inclusive ranges.
+#[derive(Debug, Eq, PartialEq, Clone)]
+pub struct IntervalSet<T> {
+    ranges: Vec<RangeInclusive<T>>,
+}
+
+impl<T: Discrete> Default for IntervalSet<T> {
+    fn default() -> Self {
+        IntervalSet { ranges: Vec::new() }
+    }
+}
+
+impl<T: Discrete> FromIterator<RangeInclusive<T>> for IntervalSet<T> {
+    fn from_iter<I: IntoIterator<Item = RangeInclusive<T>>>(iter: I) -> Self {
+        let mut set = IntervalSet::default();
+        iter.into_iter().for_each(|range| set.insert(range));
+        set
+    }
+}
+
+impl<T: Discrete> IntervalSet<T> {
+    pub fn new() -> Self {
+        IntervalSet::default()
+    }
+
+    pub fn ranges(&self) -> &[RangeInclusive<T>] {
+        &self.ranges
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.ranges.is_empty()
+    }
+
+    /// Number of values in the set.
+    pub fn len(&self) -> u64 {
+        self.ranges
+            .iter()
+            .map(|range| T::count(*range.start(), *range.end()))
+            .fold(0, u64::saturating_add)
+    }
+
+    pub fn contains(&self, value: &T) -> bool {
+        let index = self.ranges.partition_point(|range| range.end() < value);
+        self.ranges
+            .get(index)
+            .is_some_and(|range| range.start() <= value)
+    }
+
+    /// Count the values in the set with a single merge-join pass, `sorted` must be ascending.
+    pub fn count_contained(&self, sorted: &[T]) -> usize {
+        let mut ranges = self.ranges.iter().peekable();
+
+        sorted
+            .iter()
+            .filter(|value| {
+                while ranges.next_if(|range| range.end() < *value).is_some() {}
+                ranges.peek().is_some_and(|range| range.start() <= *value)
+            })
+            .count()
+    }
+
+    pub fn insert(&mut self, range: RangeInclusive<T>) {
+        let (mut start, mut end) = (*range.start(), *range.end());
+        if start > end {
+            return;
+        }
+
+        let first = self
+            .ranges
+            .partition_point(|existing| existing.end().successor() < start);
+        let last = self
+            .ranges
+            .partition_point(|existing| *existing.start() <= end.successor());
+
+        if first < last {
+            start = start.min(*self.ranges[first].start());
+            end = end.max(*self.ranges[last - 1].end());
+        }
+
+        self.ranges.splice(first..last, [start..=end]);
+    }
+
+    pub fn union(&self, other: &IntervalSet<T>) -> IntervalSet<T> {
+        let mut union = self.clone();
+        other
+            .ranges
+            .iter()
+            .for_each(|range| union.insert(range.clone()));
+        union
+    }
+
+    pub fn intersection(&self, other: &IntervalSet<T>) -> IntervalSet<T> {
+        let mut ranges = Vec::new();
+        let (mut left, mut right) = (0, 0);
+
+        while let (Some(a), Some(b)) = (self.ranges.get(left), other.ranges.get(right)) {
+            let start = *a.start().max(b.start());
+            let end = *a.end().min(b.end());
+            if start <= end {
+                ranges.push(start..=end);
+            }
+
+            if a.end() < b.end() {
+                left += 1;
+            } else {
+                right += 1;
+            }
+        }
+
+        IntervalSet { ranges }
+    }
+
+    pub fn difference(&self, other: &IntervalSet<T>) -> IntervalSet<T> {
+        self.intersection(&other.complement(T::MIN..=T::MAX))
+    }
+
+    /// Values within `bounds` missing from the set.
+    pub fn complement(&self, bounds: RangeInclusive<T>) -> IntervalSet<T> {
+        let (lower, upper) = (*bounds.start(), *bounds.end());
+        let mut ranges = Vec::new();
+        let mut next = Some(lower);
+
+        for range in &self.ranges {
+            let Some(start) = next else {
+                break;
+            };
+            if *range.start() > upper {
+                break;
+            }
+
+            if *range.start() > start {
+                ranges.push(start..=range.start().predecessor());
+            }
+            next = (*range.end() < T::MAX).then(|| range.end().successor().max(start));
+        }
+
+        if let Some(start) = next
+            && start <= upper
+        {
+            ranges.push(start..=upper);
+        }
+
+        IntervalSet { ranges }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn set(ranges: &[RangeInclusive<i64>]) -> IntervalSet<i64> {
+        ranges.iter().cloned().collect()
+    }
+
+    #[test]
+    fn test_insert_merges() {
+        assert_eq!(
+            set(&[3..=5, 10..=14, 16..=20, 12..=18]).ranges(),
+            &[3..=5, 10..=20]
+        );
+        assert_eq!(set(&[3..=5, 6..=8]).ranges(), &[3..=8]);
+        assert_eq!(set(&[10..=12, 1..=2, 4..=5, 0..=20]).ranges(), &[0..=20]);
+        assert_eq!(set(&[1..=2, 8..=9, 4..=5]).ranges(), &[1..=2, 4..=5, 8..=9]);
+        assert_eq!(set(&[RangeInclusive::new(5, 3)]).ranges(), &[]);
+    }
+
+    #[test]
+    fn test_contains() {
+        let fresh = set(&[3..=5, 10..=20]);
+
+        assert!(!fresh.contains(&2));
+        assert!(fresh.contains(&3));
+        assert!(fresh.contains(&5));
+        assert!(!fresh.contains(&6));
+        assert!(fresh.contains(&15));
+        assert!(!fresh.contains(&21));
+    }
+
+    #[test]
+    fn test_count_contained() {
+        let fresh = set(&[3..=5, 10..=20]);
+        let values = [1, 3, 3, 5, 6, 9, 10, 17, 20, 21, 40];
+
+        assert_eq!(fresh.count_contained(&values), 6);
+        assert_eq!(
+            fresh.count_contained(&values),
+            values.iter().filter(|value| fresh.contains(value)).count()
+        );
+        assert_eq!(set(&[]).count_contained(&values), 0);
+    }
+
+    #[test]
+    fn test_len() {
+        assert_eq!(set(&[3..=5, 10..=20]).len(), 14);
+        assert_eq!(set(&[]).len(), 0);
+        assert_eq!(set(&[i64::MIN..=i64::MAX]).len(), u64::MAX);
+    }
+
+    #[test]
+    fn test_operations() {
+        let a = set(&[1..=5, 10..=15]);
+        let b = set(&[4..=11, 20..=21]);
+
+        assert_eq!(a.union(&b).ranges(), &[1..=15, 20..=21]);
+        assert_eq!(a.intersection(&b).ranges(), &[4..=5, 10..=11]);
+        assert_eq!(a.difference(&b).ranges(), &[1..=3, 12..=15]);
+        assert_eq!(b.difference(&a).ranges(), &[6..=9, 20..=21]);
+    }
+
+    #[test]
+    fn test_complement() {
+        let a = set(&[1..=5, 10..=15]);
+
+        assert_eq!(a.complement(0..=20).ranges(), &[0..=0, 6..=9, 16..=20]);
+        assert_eq!(a.complement(3..=12).ranges(), &[6..=9]);
+        assert_eq!(a.complement(1..=5).ranges(), &[]);
+        assert_eq!(
+            set(&[i64::MIN..=0])
+                .complement(i64::MIN..=i64::MAX)
+                .ranges(),
+            &[1..=i64::MAX]
+        );
+        assert_eq!(
+            set(&[5..=i64::MAX]).complement(0..=i64::MAX).ranges(),
+            &[0..=4]
+        );
+    }
+}
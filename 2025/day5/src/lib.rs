@@ -0,0 +1,183 @@
+mod interval_set;
+
+pub use interval_set::{Discrete, IntervalSet};
+
+use aoc_runner::{ParseError, Solution, SolveError, indentation, numbered_lines, parse_number};
+
+pub struct Day5;
+
+impl Solution for Day5 {
+    const DAY: u8 = 5;
+
+    type Input<'a> = (IntervalSet<i64>, Vec<i64>);
+    type Part1 = usize;
+    type Part2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse(input)
+    }
+
+    fn part1((fresh_ranges, ingredients): &Self::Input<'_>) -> Result<Self::Part1, SolveError> {
+        Ok(count_fresh_ingredients(fresh_ranges, ingredients))
+    }
+
+    fn part2((fresh_ranges, _): &Self::Input<'_>) -> Result<Self::Part2, SolveError> {
+        Ok(get_fresh_ingredients(fresh_ranges))
+    }
+}
+
+fn parse(input: &str) -> Result<(IntervalSet<i64>, Vec<i64>), ParseError> {
+    let mut fresh_ranges = IntervalSet::new();
+    let mut ingredients = Vec::new();
+
+    for (line_number, raw) in numbered_lines(input) {
+        let line = raw.trim();
+        let offset = indentation(raw);
+
+        if let Some((start, end)) = line.split_once('-') {
+            let end_offset = offset + start.len() + 1;
+            fresh_ranges.insert(
+                parse_number(start, offset).map_err(|e| e.at_line(line_number))?
+                    ..=parse_number(end, end_offset).map_err(|e| e.at_line(line_number))?,
+            );
+        } else {
+            ingredients.push(parse_number(line, offset).map_err(|e| e.at_line(line_number))?);
+        }
+    }
+
+    Ok((fresh_ranges, ingredients))
+}
+
+fn count_fresh_ingredients(fresh_ranges: &IntervalSet<i64>, ingredients: &[i64]) -> usize {
+    ingredients
+        .iter()
+        .filter(|ingredient| fresh_ranges.contains(ingredient))
+        .count()
+}
+
+fn get_fresh_ingredients(fresh_ranges: &IntervalSet<i64>) -> u64 {
+    fresh_ranges.len()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_range() {
+        let range = 3..=5;
+        assert!(!range.contains(&2));
+        assert!(range.contains(&3));
+        assert!(range.contains(&4));
+        assert!(range.contains(&5));
+        assert!(!range.contains(&6));
+    }
+
+    #[test]
+    fn test_parse() {
+        let test_data = "3-5
+10-14
+16-20
+12-18
+
+1
+5
+8
+11
+17
+32";
+
+        let (fresh_ranges, ingredients) = parse(test_data).expect("Invalid database");
+
+        assert_eq!(fresh_ranges.ranges(), &[3..=5, 10..=20]);
+        assert_eq!(ingredients, vec![1, 5, 8, 11, 17, 32]);
+    }
+
+    #[test]
+    fn test_parse_invalid_number() {
+        assert_eq!(
+            parse("3-5\n  10-1a\n\n1"),
+            Err(ParseError::invalid_number(6, "1a").at_line(2))
+        );
+        assert_eq!(
+            parse("3-5\n\nx"),
+            Err(ParseError::invalid_number(1, "x").at_line(3))
+        );
+    }
+
+    #[test]
+    fn test_ingredient_fresh() {
+        let fresh_ranges = IntervalSet::from_iter([3..=5]);
+
+        assert!(!fresh_ranges.contains(&2));
+        assert!(fresh_ranges.contains(&3));
+        assert!(fresh_ranges.contains(&4));
+        assert!(fresh_ranges.contains(&5));
+        assert!(!fresh_ranges.contains(&6));
+    }
+
+    #[test]
+    fn test_range_are_merged() {
+        let test_data = "3-5
+    10-14
+    16-20
+    12-18
+
+    1
+    5
+    8
+    11
+    17
+    32";
+
+        let (fresh_ranges, _) = parse(test_data).expect("Invalid database");
+
+        assert_eq!(fresh_ranges.ranges(), &[3..=5, 10..=20]);
+
+        let (fresh_ranges, _) = parse("10-12\n3-5\n6-8\n1-20\n30-31").expect("Invalid database");
+
+        assert_eq!(fresh_ranges.ranges(), &[1..=20, 30..=31]);
+    }
+
+    #[test]
+    fn test_with_example_part_1() {
+        let test_data = "3-5
+    10-14
+    16-20
+    12-18
+
+    1
+    5
+    8
+    11
+    17
+    32";
+
+        let (fresh_ranges, ingredients) = parse(test_data).expect("Invalid database");
+        let result = count_fresh_ingredients(&fresh_ranges, &ingredients);
+
+        assert_eq!(result, 3);
+    }
+
+    #[test]
+    fn test_with_example_part_2() {
+        let test_data = "3-5
+    10-14
+    16-20
+    12-18
+
+    1
+    5
+    8
+    11
+    17
+    32";
+
+        let (fresh_ranges, _) = parse(test_data).expect("Invalid database");
+        let result = get_fresh_ingredients(&fresh_ranges);
+
+        println!("{:?}", result);
+
+        assert_eq!(result, 14);
+    }
+}
@@ -0,0 +1,418 @@
+mod operator;
+mod reading;
+mod total;
+mod worksheet;
+
+pub use operator::{
+    ADD, CONCATENATE, DIVIDE, Failure, MAX, MIN, MULTIPLY, Operator, POWER, Registry, SUBTRACT,
+};
+pub use reading::{
+    BottomToTop, Columns, Diagonal, ReadingStrategy, RightToLeft, Rows, UnknownStrategy, select,
+    strategies, strategy,
+};
+pub use total::Total;
+pub use worksheet::{Block, Cell, Worksheet};
+
+use aoc_runner::{ParseError, Solution, SolveError};
+#[cfg(feature = "big-integer")]
+use num_bigint::BigInt;
+use std::fmt::{Display, Formatter};
+
+#[derive(PartialEq, Debug)]
+pub struct Problem {
+    numbers: Vec<i64>,
+    operator: Operator,
+    /// Column of the problem in the worksheet.
+    column: usize,
+}
+
+#[derive(Debug, Eq, PartialEq, Copy, Clone)]
+pub struct EvaluationError {
+    pub column: usize,
+    pub failure: Failure,
+}
+
+impl Display for EvaluationError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "[column {}] {}", self.column, self.failure)
+    }
+}
+
+impl std::error::Error for EvaluationError {}
+
+impl Problem {
+    fn execute(&self) -> Result<i128, EvaluationError> {
+        let error = |failure| EvaluationError {
+            column: self.column,
+            failure,
+        };
+
+        let (first, others) = self.numbers.split_first().ok_or(error(Failure::Empty))?;
+        others
+            .iter()
+            .try_fold(*first as i128, |value, number| {
+                (self.operator.apply)(value, *number as i128)
+            })
+            .map_err(error)
+    }
+
+    #[cfg(feature = "big-integer")]
+    fn execute_big(&self) -> Result<BigInt, EvaluationError> {
+        let error = |failure| EvaluationError {
+            column: self.column,
+            failure,
+        };
+
+        let apply = self.operator.apply_big.ok_or(error(Failure::Overflow))?;
+        let (first, others) = self.numbers.split_first().ok_or(error(Failure::Empty))?;
+        others
+            .iter()
+            .try_fold(BigInt::from(*first), |value, number| {
+                apply(&value, &BigInt::from(*number))
+            })
+            .map_err(error)
+    }
+}
+
+pub struct Day6;
+
+impl Solution for Day6 {
+    const DAY: u8 = 6;
+
+    type Input<'a> = (Vec<Problem>, Vec<Problem>);
+    type Part1 = Total;
+    type Part2 = Total;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        let worksheet = Worksheet::parse(input)?;
+        let registry = Registry::default();
+
+        Ok((
+            read(&worksheet, &Rows, &registry)?,
+            read(&worksheet, &Columns, &registry)?,
+        ))
+    }
+
+    fn part1((problems_human, _): &Self::Input<'_>) -> Result<Self::Part1, SolveError> {
+        answer(problems_human)
+    }
+
+    fn part2((_, problems_cephalopods): &Self::Input<'_>) -> Result<Self::Part2, SolveError> {
+        answer(problems_cephalopods)
+    }
+}
+
+/// The total of the problems, failing when some are left out of it.
+fn answer(problems: &[Problem]) -> Result<Total, SolveError> {
+    let total = Total::of(problems);
+    if total.is_complete() {
+        Ok(total)
+    } else {
+        Err(SolveError::new(total.to_string()))
+    }
+}
+
+/// Sum the problems of the worksheet read with the strategy.
+pub fn solve_with(input: &str, strategy: &dyn ReadingStrategy) -> Result<Total, ParseError> {
+    let problems = read(&Worksheet::parse(input)?, strategy, &Registry::default())?;
+    Ok(Total::of(&problems))
+}
+
+/// Sum the problems of the worksheet read with each strategy, the worksheet being parsed once.
+pub fn compare_readings(
+    input: &str,
+    strategies: &[Box<dyn ReadingStrategy>],
+) -> Result<Vec<(&'static str, Total)>, ParseError> {
+    let worksheet = Worksheet::parse(input)?;
+    let registry = Registry::default();
+
+    strategies
+        .iter()
+        .map(|strategy| {
+            let problems = read(&worksheet, strategy.as_ref(), &registry)?;
+            Ok((strategy.name(), Total::of(&problems)))
+        })
+        .collect()
+}
+
+fn read(
+    worksheet: &Worksheet,
+    strategy: &dyn ReadingStrategy,
+    registry: &Registry,
+) -> Result<Vec<Problem>, ParseError> {
+    worksheet
+        .blocks
+        .iter()
+        .map(|block| {
+            Ok(Problem {
+                numbers: strategy.read(block)?,
+                operator: registry.resolve(&block.operation)?,
+                column: block.column,
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+fn parse(input: &str, strategy: &dyn ReadingStrategy) -> Result<Vec<Problem>, ParseError> {
+    read(&Worksheet::parse(input)?, strategy, &Registry::default())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    static TEST_DATA: &str = "
+123 328  51 64
+ 45 64  387 23
+  6 98  215 314
+*   +   *   +  ";
+
+    #[test]
+    fn test_parse_human() {
+        let result = parse(TEST_DATA, &Rows).expect("Invalid worksheet");
+
+        assert_eq!(
+            result,
+            vec![
+                Problem {
+                    numbers: vec![123, 45, 6],
+                    operator: MULTIPLY,
+                    column: 1
+                },
+                Problem {
+                    numbers: vec![328, 64, 98],
+                    operator: ADD,
+                    column: 5
+                },
+                Problem {
+                    numbers: vec![51, 387, 215],
+                    operator: MULTIPLY,
+                    column: 9
+                },
+                Problem {
+                    numbers: vec![64, 23, 314],
+                    operator: ADD,
+                    column: 13
+                }
+            ]
+        );
+    }
+
+    #[test]
+    fn test_parse_cephalopods() {
+        let result = parse(TEST_DATA, &Columns).expect("Invalid worksheet");
+
+        assert_eq!(
+            result,
+            vec![
+                Problem {
+                    numbers: vec![1, 24, 356],
+                    operator: MULTIPLY,
+                    column: 1
+                },
+                Problem {
+                    numbers: vec![369, 248, 8],
+                    operator: ADD,
+                    column: 5
+                },
+                Problem {
+                    numbers: vec![32, 581, 175],
+                    operator: MULTIPLY,
+                    column: 9
+                },
+                Problem {
+                    numbers: vec![623, 431, 4],
+                    operator: ADD,
+                    column: 13
+                }
+            ]
+        );
+    }
+
+    #[test]
+    fn test_execute() {
+        assert_eq!(
+            Problem {
+                numbers: vec![123, 45, 6],
+                operator: MULTIPLY,
+                column: 1
+            }
+            .execute(),
+            Ok(33210)
+        );
+        assert_eq!(
+            Problem {
+                numbers: vec![328, 64, 98],
+                operator: ADD,
+                column: 5
+            }
+            .execute(),
+            Ok(490)
+        );
+        assert_eq!(
+            Problem {
+                numbers: vec![51, 387, 215],
+                operator: MULTIPLY,
+                column: 9
+            }
+            .execute(),
+            Ok(4243455)
+        );
+        assert_eq!(
+            Problem {
+                numbers: vec![64, 23, 314],
+                operator: ADD,
+                column: 13
+            }
+            .execute(),
+            Ok(401)
+        );
+    }
+
+    #[test]
+    fn test_example_part_1() {
+        let problems = parse(TEST_DATA, &Rows).expect("Invalid worksheet");
+
+        assert_eq!(Total::of(&problems).sum, Some(4277556))
+    }
+
+    #[test]
+    fn test_example_part_2() {
+        let problems = parse(TEST_DATA, &Columns).expect("Invalid worksheet");
+
+        assert_eq!(Total::of(&problems).sum, Some(3263827))
+    }
+
+    #[test]
+    fn test_parse_invalid_number() {
+        let test_data = "
+123 328
+ 4x 64
+*   +  ";
+
+        assert_eq!(
+            parse(test_data, &Rows),
+            Err(ParseError::invalid_number(2, "4x").at_line(3))
+        );
+        assert_eq!(
+            parse(test_data, &Columns),
+            Err(ParseError::invalid_number(3, "3x").at_line(3))
+        );
+        assert_eq!(
+            parse(test_data, &BottomToTop),
+            Err(ParseError::invalid_number(3, "x3").at_line(3))
+        );
+    }
+
+    #[test]
+    fn test_execute_other_operators() {
+        let problem = |numbers: Vec<i64>, operator| Problem {
+            numbers,
+            operator,
+            column: 7,
+        };
+
+        assert_eq!(problem(vec![20, 5, 3], SUBTRACT).execute(), Ok(12));
+        assert_eq!(problem(vec![60, 5, 3], DIVIDE).execute(), Ok(4));
+        assert_eq!(problem(vec![7, 2, 9], MIN).execute(), Ok(2));
+        assert_eq!(problem(vec![7, 2, 9], MAX).execute(), Ok(9));
+        assert_eq!(problem(vec![2, 3, 2], POWER).execute(), Ok(64));
+        assert_eq!(problem(vec![12, 3, 45], CONCATENATE).execute(), Ok(12345));
+        assert_eq!(
+            problem(vec![60, 7], DIVIDE).execute(),
+            Err(EvaluationError {
+                column: 7,
+                failure: Failure::Remainder
+            })
+        );
+        assert_eq!(
+            problem(vec![i64::MAX, i64::MAX, i64::MAX], MULTIPLY)
+                .execute()
+                .map_err(|e| e.to_string()),
+            Err("[column 7] overflow".to_string())
+        );
+        assert_eq!(
+            problem(vec![], ADD).execute().map_err(|e| e.failure),
+            Err(Failure::Empty)
+        );
+    }
+
+    #[test]
+    fn test_parse_unknown_operator() {
+        let test_data = "
+123 328
+ 45 64
+*    ?";
+
+        assert_eq!(
+            parse(test_data, &Rows),
+            Err(ParseError::unexpected(6, "?", "an operator").at_line(4))
+        );
+        assert_eq!(
+            read(
+                &Worksheet::parse(test_data).expect("Invalid worksheet"),
+                &Rows,
+                &Registry::default().lenient()
+            )
+            .map(|problems| problems[1].operator),
+            Ok(ADD)
+        );
+    }
+
+    #[test]
+    fn test_solve_with() {
+        let totals: Vec<(&str, String)> = strategies()
+            .iter()
+            .map(|strategy| {
+                let total = solve_with(TEST_DATA, strategy.as_ref()).expect("Invalid worksheet");
+                (strategy.name(), total.to_string())
+            })
+            .collect();
+
+        assert_eq!(totals[0], ("rows", "4277556".to_string()));
+        assert_eq!(totals[1], ("columns", "3263827".to_string()));
+        assert_eq!(totals[2], ("right-to-left", "3263827".to_string()));
+        assert_eq!(totals.len(), 5);
+    }
+
+    #[test]
+    fn test_compare_readings() {
+        let strategies = select(&["columns", "rows"]).expect("Unknown strategy");
+        let totals: Vec<(&str, Option<i128>)> = compare_readings(TEST_DATA, &strategies)
+            .expect("Invalid worksheet")
+            .into_iter()
+            .map(|(name, total)| (name, total.sum))
+            .collect();
+
+        assert_eq!(
+            totals,
+            vec![("columns", Some(3263827)), ("rows", Some(4277556))]
+        );
+    }
+
+    #[test]
+    fn test_parse_ragged_lines() {
+        let trimmed: String = TEST_DATA
+            .lines()
+            .map(|line| line.trim_end().to_string() + "\n")
+            .collect();
+
+        assert_eq!(parse(&trimmed, &Rows), parse(TEST_DATA, &Rows));
+        assert_eq!(parse(&trimmed, &Columns), parse(TEST_DATA, &Columns));
+    }
+
+    #[test]
+    fn test_parse_multibyte_character() {
+        let test_data = "12  3é\n4   56\n*   + ";
+
+        assert_eq!(
+            parse(test_data, &Rows),
+            Err(ParseError::invalid_number(5, "3é").at_line(1))
+        );
+        assert_eq!(
+            parse(test_data, &Columns),
+            Err(ParseError::invalid_number(6, "é6").at_line(1))
+        );
+    }
+}
@@ -0,0 +1,309 @@
+use crate::Cell;
+use aoc_runner::{ParseError, indentation};
+#[cfg(feature = "big-integer")]
+use num_bigint::BigInt;
+use std::fmt::{Display, Formatter};
+
+/// Why a problem has no value.
+#[derive(Debug, Eq, PartialEq, Copy, Clone)]
+pub enum Failure {
+    Overflow,
+    DivisionByZero,
+    /// The division leaves a remainder.
+    Remainder,
+    /// The operator is not defined for a negative number.
+    NegativeOperand,
+    /// The problem has no number.
+    Empty,
+}
+
+impl Display for Failure {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let reason = match self {
+            Failure::Overflow => "overflow",
+            Failure::DivisionByZero => "division by zero",
+            Failure::Remainder => "division with a remainder",
+            Failure::NegativeOperand => "negative operand",
+            Failure::Empty => "no number",
+        };
+        write!(f, "{reason}")
+    }
+}
+
+#[cfg(feature = "big-integer")]
+type BigOperation = fn(&BigInt, &BigInt) -> Result<BigInt, Failure>;
+
+/// A binary operation folded over the numbers of a problem from left to right.
+#[derive(Debug, Copy, Clone)]
+pub struct Operator {
+    pub symbol: &'static str,
+    pub apply: fn(i128, i128) -> Result<i128, Failure>,
+    /// The same operation on big integers, used when `apply` overflows.
+    #[cfg(feature = "big-integer")]
+    pub apply_big: Option<BigOperation>,
+}
+
+impl Operator {
+    pub fn new(symbol: &'static str, apply: fn(i128, i128) -> Result<i128, Failure>) -> Self {
+        Operator {
+            symbol,
+            apply,
+            #[cfg(feature = "big-integer")]
+            apply_big: None,
+        }
+    }
+}
+
+impl PartialEq for Operator {
+    fn eq(&self, other: &Self) -> bool {
+        self.symbol == other.symbol
+    }
+}
+
+impl Eq for Operator {}
+
+pub const ADD: Operator = Operator {
+    symbol: "+",
+    apply: |a, b| a.checked_add(b).ok_or(Failure::Overflow),
+    #[cfg(feature = "big-integer")]
+    apply_big: Some(|a, b| Ok(a + b)),
+};
+
+pub const MULTIPLY: Operator = Operator {
+    symbol: "*",
+    apply: |a, b| a.checked_mul(b).ok_or(Failure::Overflow),
+    #[cfg(feature = "big-integer")]
+    apply_big: Some(|a, b| Ok(a * b)),
+};
+
+pub const SUBTRACT: Operator = Operator {
+    symbol: "-",
+    apply: |a, b| a.checked_sub(b).ok_or(Failure::Overflow),
+    #[cfg(feature = "big-integer")]
+    apply_big: Some(|a, b| Ok(a - b)),
+};
+
+pub const DIVIDE: Operator = Operator {
+    symbol: "/",
+    apply: |a, b| match a.checked_rem(b) {
+        None if b == 0 => Err(Failure::DivisionByZero),
+        None => Err(Failure::Overflow),
+        Some(0) => Ok(a / b),
+        Some(_) => Err(Failure::Remainder),
+    },
+    #[cfg(feature = "big-integer")]
+    apply_big: Some(|a, b| {
+        if *b == BigInt::ZERO {
+            Err(Failure::DivisionByZero)
+        } else if a % b != BigInt::ZERO {
+            Err(Failure::Remainder)
+        } else {
+            Ok(a / b)
+        }
+    }),
+};
+
+pub const MIN: Operator = Operator {
+    symbol: "<",
+    apply: |a, b| Ok(a.min(b)),
+    #[cfg(feature = "big-integer")]
+    apply_big: Some(|a, b| Ok(a.min(b).clone())),
+};
+
+pub const MAX: Operator = Operator {
+    symbol: ">",
+    apply: |a, b| Ok(a.max(b)),
+    #[cfg(feature = "big-integer")]
+    apply_big: Some(|a, b| Ok(a.max(b).clone())),
+};
+
+pub const POWER: Operator = Operator {
+    symbol: "^",
+    apply: |a, b| {
+        let exponent = u32::try_from(b).map_err(|_| match b {
+            ..0 => Failure::NegativeOperand,
+            _ => Failure::Overflow,
+        })?;
+        a.checked_pow(exponent).ok_or(Failure::Overflow)
+    },
+    #[cfg(feature = "big-integer")]
+    apply_big: Some(|a, b| match u32::try_from(b) {
+        Ok(exponent) => Ok(a.pow(exponent)),
+        Err(_) if *b < BigInt::ZERO => Err(Failure::NegativeOperand),
+        Err(_) => Err(Failure::Overflow),
+    }),
+};
+
+/// Write the digits of the second number after the first one.
+pub const CONCATENATE: Operator = Operator {
+    symbol: "|",
+    apply: |a, b| {
+        if a < 0 || b < 0 {
+            return Err(Failure::NegativeOperand);
+        }
+        let shift = 10i128
+            .checked_pow(b.checked_ilog10().unwrap_or(0) + 1)
+            .ok_or(Failure::Overflow)?;
+        a.checked_mul(shift)
+            .and_then(|a| a.checked_add(b))
+            .ok_or(Failure::Overflow)
+    },
+    #[cfg(feature = "big-integer")]
+    apply_big: Some(|a, b| {
+        if *a < BigInt::ZERO || *b < BigInt::ZERO {
+            return Err(Failure::NegativeOperand);
+        }
+        let digits = b.to_string().len() as u32;
+        Ok(a * BigInt::from(10).pow(digits) + b)
+    }),
+};
+
+/// Operators known by their symbol.
+#[derive(Debug, Eq, PartialEq, Clone)]
+pub struct Registry {
+    operators: Vec<Operator>,
+    lenient: bool,
+}
+
+impl Default for Registry {
+    /// Every built-in operator, rejecting the unknown symbols.
+    fn default() -> Self {
+        Registry {
+            operators: vec![
+                ADD,
+                MULTIPLY,
+                SUBTRACT,
+                DIVIDE,
+                MIN,
+                MAX,
+                POWER,
+                CONCATENATE,
+            ],
+            lenient: false,
+        }
+    }
+}
+
+impl Registry {
+    /// A registry without any operator.
+    pub fn new() -> Self {
+        Registry {
+            operators: Vec::new(),
+            lenient: false,
+        }
+    }
+
+    /// Read the unknown symbols as an addition instead of rejecting them.
+    pub fn lenient(mut self) -> Self {
+        self.lenient = true;
+        self
+    }
+
+    /// Add the operator, replacing the one with the same symbol.
+    pub fn register(mut self, operator: Operator) -> Self {
+        self.operators
+            .retain(|existing| existing.symbol != operator.symbol);
+        self.operators.push(operator);
+        self
+    }
+
+    pub fn get(&self, symbol: &str) -> Option<Operator> {
+        self.operators
+            .iter()
+            .find(|operator| operator.symbol == symbol)
+            .copied()
+    }
+
+    pub fn resolve(&self, cell: &Cell) -> Result<Operator, ParseError> {
+        let symbol = cell.text.trim();
+        match self.get(symbol) {
+            Some(operator) => Ok(operator),
+            None if self.lenient => Ok(ADD),
+            None => Err(ParseError::unexpected(
+                cell.column + indentation(cell.text) - 1,
+                symbol,
+                "an operator",
+            )
+            .at_line(cell.line)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn cell(text: &str) -> Cell<'_> {
+        Cell {
+            text,
+            line: 4,
+            column: 9,
+            offset: 8,
+        }
+    }
+
+    #[test]
+    fn test_operators() {
+        assert_eq!((SUBTRACT.apply)(3, 5), Ok(-2));
+        assert_eq!((DIVIDE.apply)(12, 4), Ok(3));
+        assert_eq!((DIVIDE.apply)(13, 4), Err(Failure::Remainder));
+        assert_eq!((DIVIDE.apply)(13, 0), Err(Failure::DivisionByZero));
+        assert_eq!((DIVIDE.apply)(i128::MIN, -1), Err(Failure::Overflow));
+        assert_eq!((MIN.apply)(3, 5), Ok(3));
+        assert_eq!((MAX.apply)(3, 5), Ok(5));
+        assert_eq!((POWER.apply)(3, 4), Ok(81));
+        assert_eq!((POWER.apply)(3, -1), Err(Failure::NegativeOperand));
+        assert_eq!((POWER.apply)(10, 39), Err(Failure::Overflow));
+        assert_eq!((CONCATENATE.apply)(12, 345), Ok(12345));
+        assert_eq!((CONCATENATE.apply)(12, 0), Ok(120));
+        assert_eq!(
+            (CONCATENATE.apply)(i128::MAX / 10, 8),
+            Err(Failure::Overflow)
+        );
+        assert_eq!((MULTIPLY.apply)(i128::MAX, 2), Err(Failure::Overflow));
+    }
+
+    #[cfg(feature = "big-integer")]
+    #[test]
+    fn test_big_operators() {
+        let big = |operator: Operator, a: i128, b: i128| {
+            (operator.apply_big.expect("No big integer operation"))(
+                &BigInt::from(a),
+                &BigInt::from(b),
+            )
+        };
+
+        assert_eq!(big(MULTIPLY, i128::MAX, 2), Ok(BigInt::from(i128::MAX) * 2));
+        assert_eq!(big(DIVIDE, 13, 4), Err(Failure::Remainder));
+        assert_eq!(big(DIVIDE, 12, 4), Ok(BigInt::from(3)));
+        assert_eq!(big(POWER, 10, 40), Ok(BigInt::from(10).pow(40)));
+        assert_eq!(big(CONCATENATE, 12, 0), Ok(BigInt::from(120)));
+        assert_eq!(big(MIN, 3, 5), Ok(BigInt::from(3)));
+        assert!(Operator::new("%", |a, _| Ok(a)).apply_big.is_none());
+    }
+
+    #[test]
+    fn test_resolve() {
+        let registry = Registry::default();
+
+        assert_eq!(registry.resolve(&cell(" ^ ")), Ok(POWER));
+        assert_eq!(
+            registry.resolve(&cell("  ?")),
+            Err(ParseError::unexpected(11, "?", "an operator").at_line(4))
+        );
+        assert_eq!(registry.lenient().resolve(&cell("?")), Ok(ADD));
+    }
+
+    #[test]
+    fn test_register() {
+        let modulo = Operator::new("%", |a, b| a.checked_rem(b).ok_or(Failure::DivisionByZero));
+        let registry = Registry::new().register(ADD).register(modulo);
+
+        assert_eq!(
+            registry.get("%").map(|operator| (operator.apply)(7, 3)),
+            Some(Ok(1))
+        );
+        assert_eq!(registry.get("*"), None);
+        assert!(registry.resolve(&cell("*")).is_err());
+    }
+}
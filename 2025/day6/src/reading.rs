@@ -0,0 +1,285 @@
+use crate::Block;
+use aoc_runner::{ParseError, indentation, parse_number};
+use std::fmt::{Display, Formatter};
+use std::iter;
+
+/// How the numbers of a problem are written in its block.
+pub trait ReadingStrategy {
+    /// Name selecting the strategy from the command line.
+    fn name(&self) -> &'static str;
+
+    fn read(&self, block: &Block) -> Result<Vec<i64>, ParseError>;
+}
+
+/// One number per line, the human way.
+pub struct Rows;
+
+/// One number per column from left to right, digits from top to bottom, the cephalopod way.
+pub struct Columns;
+
+/// One number per column from right to left, digits from top to bottom.
+pub struct RightToLeft;
+
+/// One number per column from left to right, digits from bottom to top.
+pub struct BottomToTop;
+
+/// One number per diagonal going down and right, from the bottom left corner to the top
+/// right one.
+///
+/// Blanks are skipped, so digits on both sides of a blank make a single number.
+pub struct Diagonal;
+
+/// Every built-in strategy.
+pub fn strategies() -> Vec<Box<dyn ReadingStrategy>> {
+    vec![
+        Box::new(Rows),
+        Box::new(Columns),
+        Box::new(RightToLeft),
+        Box::new(BottomToTop),
+        Box::new(Diagonal),
+    ]
+}
+
+pub fn strategy(name: &str) -> Option<Box<dyn ReadingStrategy>> {
+    strategies()
+        .into_iter()
+        .find(|strategy| strategy.name() == name)
+}
+
+#[derive(Debug, Eq, PartialEq, Clone)]
+pub struct UnknownStrategy(pub String);
+
+impl Display for UnknownStrategy {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Unknown reading strategy [{}]", self.0)
+    }
+}
+
+impl std::error::Error for UnknownStrategy {}
+
+/// The strategies with these names, or every built-in one when a name is `all`.
+pub fn select<S: AsRef<str>>(
+    names: &[S],
+) -> Result<Vec<Box<dyn ReadingStrategy>>, UnknownStrategy> {
+    if names.iter().any(|name| name.as_ref() == "all") {
+        return Ok(strategies());
+    }
+
+    names
+        .iter()
+        .map(|name| strategy(name.as_ref()).ok_or(UnknownStrategy(name.as_ref().to_string())))
+        .collect()
+}
+
+/// Characters of the numbers, the missing end of a line being spaces.
+///
+/// A cell never has more characters than its block is wide, the worksheet cuts it there.
+fn characters(block: &Block) -> Vec<Vec<char>> {
+    block
+        .numbers
+        .iter()
+        .map(|cell| {
+            let mut row: Vec<char> = cell.text.chars().collect();
+            debug_assert!(row.len() <= block.width, "Cell wider than its block");
+            row.extend(iter::repeat_n(' ', block.width.saturating_sub(row.len())));
+            row
+        })
+        .collect()
+}
+
+fn first_line(block: &Block) -> usize {
+    block.numbers.first().map(|cell| cell.line).unwrap_or(1)
+}
+
+fn read_columns(block: &Block, bottom_to_top: bool) -> Vec<Result<i64, ParseError>> {
+    let mut rows: Vec<(usize, Vec<char>)> = block
+        .numbers
+        .iter()
+        .map(|cell| cell.line)
+        .zip(characters(block))
+        .collect();
+    if bottom_to_top {
+        rows.reverse();
+    }
+
+    (0..block.width)
+        .map(|index| {
+            let cells: Vec<(usize, char)> =
+                rows.iter().map(|(line, row)| (*line, row[index])).collect();
+            let number: String = cells.iter().map(|(_, c)| c).collect();
+            parse_number(number.trim(), block.column + index).map_err(|e| {
+                let digits = cells.iter().skip_while(|(_, c)| *c == ' ');
+                let line = digits
+                    .clone()
+                    .find(|(_, c)| !c.is_ascii_digit())
+                    .or(digits.clone().next())
+                    .map(|(line, _)| *line)
+                    .unwrap_or(first_line(block));
+                e.at_line(line)
+            })
+        })
+        .collect()
+}
+
+impl ReadingStrategy for Rows {
+    fn name(&self) -> &'static str {
+        "rows"
+    }
+
+    fn read(&self, block: &Block) -> Result<Vec<i64>, ParseError> {
+        block
+            .numbers
+            .iter()
+            .map(|cell| {
+                parse_number(cell.text.trim(), cell.column + indentation(cell.text) - 1)
+                    .map_err(|e| e.at_line(cell.line))
+            })
+            .collect()
+    }
+}
+
+impl ReadingStrategy for Columns {
+    fn name(&self) -> &'static str {
+        "columns"
+    }
+
+    fn read(&self, block: &Block) -> Result<Vec<i64>, ParseError> {
+        read_columns(block, false).into_iter().collect()
+    }
+}
+
+impl ReadingStrategy for RightToLeft {
+    fn name(&self) -> &'static str {
+        "right-to-left"
+    }
+
+    fn read(&self, block: &Block) -> Result<Vec<i64>, ParseError> {
+        read_columns(block, false).into_iter().rev().collect()
+    }
+}
+
+impl ReadingStrategy for BottomToTop {
+    fn name(&self) -> &'static str {
+        "bottom-to-top"
+    }
+
+    fn read(&self, block: &Block) -> Result<Vec<i64>, ParseError> {
+        read_columns(block, true).into_iter().collect()
+    }
+}
+
+impl ReadingStrategy for Diagonal {
+    fn name(&self) -> &'static str {
+        "diagonal"
+    }
+
+    fn read(&self, block: &Block) -> Result<Vec<i64>, ParseError> {
+        let rows = characters(block);
+        let height = rows.len() as isize;
+
+        (1 - height..block.width as isize)
+            .filter_map(|shift| {
+                let cells: Vec<(usize, usize, char)> = rows
+                    .iter()
+                    .enumerate()
+                    .filter_map(|(row, characters)| {
+                        let column = usize::try_from(row as isize + shift).ok()?;
+                        characters.get(column).map(|c| (row, column, *c))
+                    })
+                    .filter(|(_, _, c)| *c != ' ')
+                    .collect();
+
+                let first = *cells.first()?;
+                let (row, column, _) = cells
+                    .iter()
+                    .copied()
+                    .find(|(_, _, c)| !c.is_ascii_digit())
+                    .unwrap_or(first);
+                let number: String = cells.iter().map(|(_, _, c)| c).collect();
+                Some(
+                    parse_number(&number, block.column + column)
+                        .map_err(|e| e.at_line(block.numbers[row].line)),
+                )
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Worksheet;
+
+    fn read(strategy: &dyn ReadingStrategy, input: &str) -> Result<Vec<i64>, ParseError> {
+        let worksheet = Worksheet::parse(input).expect("Invalid worksheet");
+        strategy.read(&worksheet.blocks[0])
+    }
+
+    const BLOCK: &str = "12 \n345\n 6 \n+  ";
+
+    #[test]
+    fn test_strategies() {
+        assert_eq!(read(&Rows, BLOCK), Ok(vec![12, 345, 6]));
+        assert_eq!(read(&Columns, BLOCK), Ok(vec![13, 246, 5]));
+        assert_eq!(read(&RightToLeft, BLOCK), Ok(vec![5, 246, 13]));
+        assert_eq!(read(&BottomToTop, BLOCK), Ok(vec![31, 642, 5]));
+        assert_eq!(read(&Diagonal, BLOCK), Ok(vec![36, 14, 25]));
+    }
+
+    #[test]
+    fn test_diagonal_error() {
+        assert_eq!(
+            read(&Diagonal, "12 \n3x5\n 6 \n+  "),
+            Err(ParseError::invalid_number(2, "1x").at_line(2))
+        );
+    }
+
+    #[test]
+    fn test_diagonal_skips_blanks() {
+        assert_eq!(read(&Diagonal, "1  4\n7\n 82\n+   "), Ok(vec![78, 12, 4]));
+    }
+
+    #[test]
+    fn test_strategy() {
+        let names: Vec<&str> = strategies()
+            .iter()
+            .map(|strategy| strategy.name())
+            .collect();
+
+        assert_eq!(
+            names,
+            vec![
+                "rows",
+                "columns",
+                "right-to-left",
+                "bottom-to-top",
+                "diagonal"
+            ]
+        );
+        assert_eq!(
+            strategy("diagonal").map(|strategy| strategy.name()),
+            Some("diagonal")
+        );
+        assert!(strategy("spiral").is_none());
+    }
+
+    #[test]
+    fn test_select() {
+        let names = |strategies: Vec<Box<dyn ReadingStrategy>>| {
+            strategies
+                .iter()
+                .map(|strategy| strategy.name())
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(
+            select(&["diagonal", "rows"]).map(names),
+            Ok(vec!["diagonal", "rows"])
+        );
+        assert_eq!(select(&["rows", "all"]).map(names).map(|n| n.len()), Ok(5));
+        assert_eq!(
+            select(&["rows", "spiral"]).map(names),
+            Err(UnknownStrategy("spiral".to_string()))
+        );
+    }
+}
@@ -0,0 +1,264 @@
+#[cfg(feature = "big-integer")]
+use crate::Failure;
+use crate::{EvaluationError, Problem};
+#[cfg(feature = "big-integer")]
+use num_bigint::BigInt;
+use std::fmt::{Display, Formatter};
+
+/// Sum of the problems of a worksheet and the problems left out of it.
+#[derive(Debug, Eq, PartialEq, Clone, Default)]
+#[non_exhaustive]
+pub struct Total {
+    /// Sum of the solved problems, `None` when it does not fit in `i128`.
+    pub sum: Option<i128>,
+    /// Sum of the solved problems, whatever its size.
+    #[cfg(feature = "big-integer")]
+    pub big_sum: BigInt,
+    /// Columns of the problems overflowing `i128` that were solved with big integers.
+    pub overflowed: Vec<usize>,
+    /// Problems without a value, missing from the sum.
+    pub failures: Vec<EvaluationError>,
+}
+
+/// An `i128` sum wrapping around, with the number of times it went past either bound,
+/// which is exact whatever the order of the values.
+#[derive(Debug, Default)]
+struct WideSum {
+    wrapped: i128,
+    carry: i64,
+}
+
+impl WideSum {
+    fn add(&mut self, value: i128) {
+        let (wrapped, overflow) = self.wrapped.overflowing_add(value);
+        if overflow {
+            self.carry += value.signum() as i64;
+        }
+        self.wrapped = wrapped;
+    }
+
+    #[cfg(not(feature = "big-integer"))]
+    fn exact(&self) -> Option<i128> {
+        (self.carry == 0).then_some(self.wrapped)
+    }
+
+    #[cfg(feature = "big-integer")]
+    fn big(&self) -> BigInt {
+        BigInt::from(self.wrapped) + (BigInt::from(self.carry) << 128)
+    }
+}
+
+impl Total {
+    pub fn of(problems: &[Problem]) -> Total {
+        let mut total = Total::default();
+        let mut sum = WideSum::default();
+        for problem in problems {
+            match problem.execute() {
+                Ok(value) => sum.add(value),
+                Err(error) => total.fail(problem, error),
+            }
+        }
+        total.finish(sum)
+    }
+
+    /// Whether every problem was solved and the sum is exact.
+    pub fn is_complete(&self) -> bool {
+        #[cfg(feature = "big-integer")]
+        let exact = true;
+        #[cfg(not(feature = "big-integer"))]
+        let exact = self.sum.is_some();
+
+        exact && self.failures.is_empty()
+    }
+
+    #[cfg(not(feature = "big-integer"))]
+    fn fail(&mut self, _: &Problem, error: EvaluationError) {
+        self.failures.push(error);
+    }
+
+    #[cfg(feature = "big-integer")]
+    fn fail(&mut self, problem: &Problem, error: EvaluationError) {
+        if error.failure != Failure::Overflow {
+            self.failures.push(error);
+        } else {
+            match problem.execute_big() {
+                Ok(value) => {
+                    self.big_sum += value;
+                    self.overflowed.push(problem.column);
+                }
+                Err(error) => self.failures.push(error),
+            }
+        }
+    }
+
+    #[cfg(not(feature = "big-integer"))]
+    fn finish(mut self, sum: WideSum) -> Self {
+        self.sum = sum.exact();
+        self
+    }
+
+    #[cfg(feature = "big-integer")]
+    fn finish(mut self, sum: WideSum) -> Self {
+        self.big_sum += sum.big();
+        self.sum = i128::try_from(&self.big_sum).ok();
+        self
+    }
+
+    #[cfg(not(feature = "big-integer"))]
+    fn write_sum(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self.sum {
+            Some(sum) => write!(f, "{sum}"),
+            None => write!(f, "no total, the sum does not fit in {} bits", i128::BITS),
+        }
+    }
+
+    #[cfg(feature = "big-integer")]
+    fn write_sum(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.big_sum)
+    }
+}
+
+fn problems(count: usize) -> String {
+    match count {
+        1 => String::from("1 problem"),
+        count => format!("{count} problems"),
+    }
+}
+
+impl Display for Total {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        if self.failures.is_empty() {
+            self.write_sum(f)?;
+        } else {
+            write!(f, "no total")?;
+        }
+
+        if !self.overflowed.is_empty() {
+            let columns: Vec<String> = self.overflowed.iter().map(usize::to_string).collect();
+            write!(
+                f,
+                ", {} solved with big integers at columns {}",
+                problems(columns.len()),
+                columns.join(", ")
+            )?;
+        }
+
+        if !self.failures.is_empty() {
+            let failures: Vec<String> = self.failures.iter().map(|e| e.to_string()).collect();
+            write!(
+                f,
+                ", {} left out: {}",
+                problems(failures.len()),
+                failures.join(", ")
+            )?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{ADD, DIVIDE, MULTIPLY};
+
+    fn problems() -> Vec<Problem> {
+        vec![
+            Problem {
+                numbers: vec![2, 3],
+                operator: ADD,
+                column: 1,
+            },
+            Problem {
+                numbers: vec![i64::MAX, i64::MAX, 4],
+                operator: MULTIPLY,
+                column: 5,
+            },
+            Problem {
+                numbers: vec![7, 2],
+                operator: DIVIDE,
+                column: 9,
+            },
+        ]
+    }
+
+    fn square(sign: i64, column: usize) -> Problem {
+        Problem {
+            numbers: vec![sign * i64::MAX, i64::MAX],
+            operator: MULTIPLY,
+            column,
+        }
+    }
+
+    #[test]
+    fn test_total() {
+        let total = Total::of(&problems()[..1]);
+
+        assert_eq!(total.sum, Some(5));
+        assert!(total.is_complete());
+        assert_eq!(total.to_string(), "5");
+    }
+
+    #[test]
+    fn test_sum_whatever_the_order() {
+        let expected = Some(2 * (i64::MAX as i128).pow(2));
+        let mut problems = vec![square(1, 1), square(1, 2), square(1, 3), square(-1, 4)];
+
+        assert_eq!(Total::of(&problems).sum, expected);
+        problems.reverse();
+        assert_eq!(Total::of(&problems).sum, expected);
+        assert!(Total::of(&problems).is_complete());
+    }
+
+    #[cfg(not(feature = "big-integer"))]
+    #[test]
+    fn test_total_with_failures() {
+        let total = Total::of(&problems());
+
+        assert_eq!(total.sum, Some(5));
+        assert!(!total.is_complete());
+        assert_eq!(
+            total.to_string(),
+            "no total, 2 problems left out: [column 5] overflow, [column 9] division with a remainder"
+        );
+
+        let total = Total::of(&problems()[1..2]);
+        assert_eq!(
+            total.to_string(),
+            "no total, 1 problem left out: [column 5] overflow"
+        );
+    }
+
+    #[cfg(not(feature = "big-integer"))]
+    #[test]
+    fn test_sum_overflow() {
+        let total = Total::of(&[square(1, 1), square(1, 2), square(1, 3)]);
+
+        assert_eq!(total.sum, None);
+        assert!(total.failures.is_empty());
+        assert!(!total.is_complete());
+        assert_eq!(
+            total.to_string(),
+            "no total, the sum does not fit in 128 bits"
+        );
+    }
+
+    #[cfg(feature = "big-integer")]
+    #[test]
+    fn test_total_with_big_integers() {
+        let total = Total::of(&problems());
+
+        assert_eq!(total.big_sum, BigInt::from(i64::MAX).pow(2) * 4 + 5);
+        assert_eq!(total.sum, None);
+        assert_eq!(total.overflowed, vec![5]);
+        assert!(!total.is_complete());
+        assert_eq!(
+            total.to_string(),
+            "no total, 1 problem solved with big integers at columns 5, 1 problem left out: [column 9] division with a remainder"
+        );
+
+        let total = Total::of(&[square(1, 1), square(1, 2), square(1, 3)]);
+        assert_eq!(total.big_sum, BigInt::from(i64::MAX).pow(2) * 3);
+        assert!(total.is_complete());
+    }
+}
@@ -0,0 +1,166 @@
+use aoc_runner::{ParseError, numbered_lines};
+
+/// Text of one line inside a problem.
+#[derive(Debug, Eq, PartialEq, Copy, Clone)]
+pub struct Cell<'a> {
+    pub text: &'a str,
+    pub line: usize,
+    /// Column of the first character, starting at 1 and counted in characters.
+    pub column: usize,
+    /// Byte offset of the first character in the line.
+    pub offset: usize,
+}
+
+/// The numbers of a problem above its operation.
+#[derive(Debug, Eq, PartialEq, Clone)]
+pub struct Block<'a> {
+    pub column: usize,
+    /// Number of characters between the separator columns around the problem.
+    pub width: usize,
+    pub numbers: Vec<Cell<'a>>,
+    pub operation: Cell<'a>,
+}
+
+/// A worksheet cut into problems along the columns that are blank on every line.
+///
+/// Lines may have different lengths, the missing end of a line reads as spaces.
+#[derive(Debug, Eq, PartialEq, Clone)]
+pub struct Worksheet<'a> {
+    pub blocks: Vec<Block<'a>>,
+}
+
+struct Line<'a> {
+    number: usize,
+    text: &'a str,
+    chars: Vec<(usize, char)>,
+}
+
+impl<'a> Line<'a> {
+    fn is_blank(&self, column: usize) -> bool {
+        self.chars.get(column).is_none_or(|(_, c)| *c == ' ')
+    }
+
+    fn offset(&self, column: usize) -> usize {
+        self.chars
+            .get(column)
+            .map(|(offset, _)| *offset)
+            .unwrap_or(self.text.len())
+    }
+
+    fn cell(&self, start: usize, end: usize) -> Cell<'a> {
+        let offset = self.offset(start);
+        Cell {
+            text: &self.text[offset..self.offset(end)],
+            line: self.number,
+            column: start + 1,
+            offset,
+        }
+    }
+}
+
+impl<'a> Worksheet<'a> {
+    pub fn parse(input: &'a str) -> Result<Self, ParseError> {
+        let lines: Vec<Line> = numbered_lines(input)
+            .map(|(number, text)| Line {
+                number,
+                text,
+                chars: text.char_indices().collect(),
+            })
+            .collect();
+        let width = lines.iter().map(|line| line.chars.len()).max().unwrap_or(0);
+
+        let separators =
+            (0..width).filter(|column| lines.iter().all(|line| line.is_blank(*column)));
+        let mut bounds = Vec::new();
+        let mut start = 0;
+        for separator in separators.chain([width]) {
+            if separator > start || separator == width && bounds.is_empty() {
+                bounds.push((start, separator));
+            }
+            start = separator + 1;
+        }
+
+        let blocks = bounds
+            .into_iter()
+            .map(|(start, end)| {
+                let mut cells: Vec<Cell> = lines.iter().map(|line| line.cell(start, end)).collect();
+                let operation =
+                    cells
+                        .pop()
+                        .ok_or(ParseError::missing(start + 1, "", "an operation"))?;
+
+                Ok(Block {
+                    column: start + 1,
+                    width: end - start,
+                    numbers: cells,
+                    operation,
+                })
+            })
+            .collect::<Result<_, _>>()?;
+
+        Ok(Worksheet { blocks })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn texts<'a>(worksheet: &Worksheet<'a>) -> Vec<Vec<&'a str>> {
+        worksheet
+            .blocks
+            .iter()
+            .map(|block| {
+                block
+                    .numbers
+                    .iter()
+                    .chain([&block.operation])
+                    .map(|cell| cell.text)
+                    .collect()
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_ragged_lines() {
+        let worksheet = Worksheet::parse("123 328  51\n 45 64  387 23\n\n*   +   *")
+            .expect("Invalid worksheet");
+
+        assert_eq!(
+            texts(&worksheet),
+            vec![
+                vec!["123", " 45", "*  "],
+                vec!["328", "64 ", "+  "],
+                vec![" 51", "387", "*"],
+                vec!["", "23", ""]
+            ]
+        );
+        assert_eq!(worksheet.blocks[3].column, 13);
+        assert_eq!(worksheet.blocks[3].width, 2);
+        assert_eq!(worksheet.blocks[2].operation.line, 4);
+    }
+
+    #[test]
+    fn test_multibyte_characters() {
+        let worksheet = Worksheet::parse("é1  2\n+   *").expect("Invalid worksheet");
+
+        assert_eq!(texts(&worksheet), vec![vec!["é1", "+ "], vec!["2", "*"]]);
+        assert_eq!(
+            worksheet.blocks[1].numbers[0],
+            Cell {
+                text: "2",
+                line: 1,
+                column: 5,
+                offset: 5
+            }
+        );
+    }
+
+    #[test]
+    fn test_missing_operation() {
+        assert_eq!(
+            Worksheet::parse("\n\n"),
+            Err(ParseError::missing(1, "", "an operation"))
+        );
+    }
+}
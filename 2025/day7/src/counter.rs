@@ -0,0 +1,134 @@
+#[cfg(feature = "big-integer")]
+use num_bigint::BigUint;
+use std::fmt::{Display, Formatter};
+
+/// Arithmetic used to count the timelines.
+pub trait Counter {
+    type Value: Clone;
+
+    fn zero(&self) -> Self::Value;
+
+    fn one(&self) -> Self::Value;
+
+    /// `None` when the sum cannot be represented.
+    fn add(&self, a: &Self::Value, b: &Self::Value) -> Option<Self::Value>;
+}
+
+/// The timelines do not fit in the counter.
+#[derive(Debug, Eq, PartialEq, Copy, Clone)]
+pub struct Overflow {
+    pub line: usize,
+}
+
+impl Display for Overflow {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "The timeline count overflows at line {}", self.line)
+    }
+}
+
+impl std::error::Error for Overflow {}
+
+/// `u64` counts, failing on overflow.
+#[derive(Debug, Eq, PartialEq, Copy, Clone, Default)]
+pub struct U64;
+
+/// `u128` counts, failing on overflow.
+#[derive(Debug, Eq, PartialEq, Copy, Clone, Default)]
+pub struct U128;
+
+/// Counts modulo a number, usually a large prime, which never overflow.
+#[derive(Debug, Eq, PartialEq, Copy, Clone)]
+pub struct Modular {
+    modulus: u64,
+}
+
+/// Unbounded counts.
+#[cfg(feature = "big-integer")]
+#[derive(Debug, Eq, PartialEq, Copy, Clone, Default)]
+pub struct Big;
+
+impl Counter for U64 {
+    type Value = u64;
+
+    fn zero(&self) -> Self::Value {
+        0
+    }
+
+    fn one(&self) -> Self::Value {
+        1
+    }
+
+    fn add(&self, a: &Self::Value, b: &Self::Value) -> Option<Self::Value> {
+        a.checked_add(*b)
+    }
+}
+
+impl Counter for U128 {
+    type Value = u128;
+
+    fn zero(&self) -> Self::Value {
+        0
+    }
+
+    fn one(&self) -> Self::Value {
+        1
+    }
+
+    fn add(&self, a: &Self::Value, b: &Self::Value) -> Option<Self::Value> {
+        a.checked_add(*b)
+    }
+}
+
+impl Modular {
+    pub fn new(modulus: u64) -> Self {
+        assert!(modulus > 0, "The modulus must be positive");
+        Modular { modulus }
+    }
+}
+
+impl Counter for Modular {
+    type Value = u64;
+
+    fn zero(&self) -> Self::Value {
+        0
+    }
+
+    fn one(&self) -> Self::Value {
+        1 % self.modulus
+    }
+
+    fn add(&self, a: &Self::Value, b: &Self::Value) -> Option<Self::Value> {
+        Some(((*a as u128 + *b as u128) % self.modulus as u128) as u64)
+    }
+}
+
+#[cfg(feature = "big-integer")]
+impl Counter for Big {
+    type Value = BigUint;
+
+    fn zero(&self) -> Self::Value {
+        BigUint::ZERO
+    }
+
+    fn one(&self) -> Self::Value {
+        BigUint::from(1u8)
+    }
+
+    fn add(&self, a: &Self::Value, b: &Self::Value) -> Option<Self::Value> {
+        Some(a + b)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_add() {
+        assert_eq!(U64.add(&u64::MAX, &1), None);
+        assert_eq!(U128.add(&(u64::MAX as u128), &1), Some(1 << 64));
+        assert_eq!(Modular::new(7).add(&5, &4), Some(2));
+        assert_eq!(Modular::new(u64::MAX).add(&(u64::MAX - 1), &2), Some(1));
+        assert_eq!(Modular::new(1).one(), 0);
+    }
+}
@@ -0,0 +1,326 @@
+mod counter;
+
+#[cfg(feature = "big-integer")]
+pub use counter::Big;
+pub use counter::{Counter, Modular, Overflow, U64, U128};
+
+use aoc_runner::{ParseError, Solution, SolveError};
+use grid::{Coordinate, Grid};
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+
+#[derive(Eq, PartialEq, Debug)]
+enum Element {
+    Source,
+    Beam,
+    Splitter,
+    Empty,
+}
+
+#[derive(Eq, PartialEq, Debug)]
+pub struct Manifold {
+    source: Coordinate,
+    content: Grid<Element>,
+}
+
+impl Element {
+    fn from_symbol(symbol: char) -> Option<Element> {
+        match symbol {
+            'S' => Some(Element::Source),
+            '|' => Some(Element::Beam),
+            '^' => Some(Element::Splitter),
+            '.' => Some(Element::Empty),
+            _ => None,
+        }
+    }
+}
+
+impl Display for Element {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let symbol = match self {
+            Element::Source => 'S',
+            Element::Beam => '|',
+            Element::Splitter => '^',
+            Element::Empty => '.',
+        };
+        write!(f, "{symbol}")
+    }
+}
+
+impl FromStr for Manifold {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let content = Grid::parse(s, Element::from_symbol, "'S', '|', '^' or '.'")?;
+        let source = content
+            .iter()
+            .find(|(_, element)| **element == Element::Source)
+            .map(|(coordinate, _)| coordinate)
+            .ok_or(ParseError::missing(1, "", "a source 'S'"))?;
+
+        Ok(Manifold { source, content })
+    }
+}
+
+impl Display for Manifold {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.content)
+    }
+}
+
+impl Manifold {
+    /// Follow the beam counting timelines with `u64`, failing when they overflow.
+    pub fn launch_beam(&self) -> Result<u64, Overflow> {
+        self.launch_beam_with(&U64)
+    }
+
+    /// Follow the beam row after row, counting the timelines reaching each column.
+    pub fn launch_beam_with<C: Counter>(&self, counter: &C) -> Result<C::Value, Overflow> {
+        let width = self.content.width();
+        let mut timelines: Vec<Option<C::Value>> = vec![None; width];
+        let mut next: Vec<Option<C::Value>> = vec![None; width];
+        timelines[self.source.column] = Some(counter.one());
+
+        for row in self.source.row + 1..self.content.height() {
+            let elements = self.content.row(row);
+            let overflow = Overflow { line: row + 1 };
+            next.fill(None);
+
+            for (column, count) in timelines.iter().enumerate() {
+                let Some(count) = count else {
+                    continue;
+                };
+
+                if elements[column] == Element::Splitter {
+                    if let Some(left) = column.checked_sub(1) {
+                        merge(counter, &mut next[left], count).ok_or(overflow)?;
+                    }
+                    if let Some(right) = next.get_mut(column + 1) {
+                        merge(counter, right, count).ok_or(overflow)?;
+                    }
+                } else {
+                    merge(counter, &mut next[column], count).ok_or(overflow)?;
+                }
+            }
+
+            std::mem::swap(&mut timelines, &mut next);
+        }
+
+        timelines
+            .iter()
+            .flatten()
+            .try_fold(counter.zero(), |total, count| counter.add(&total, count))
+            .ok_or(Overflow {
+                line: self.content.height(),
+            })
+    }
+
+    /// Number of splitters hit by the beam, whatever the number of timelines, `None` when it
+    /// does not fit in `u32`.
+    pub fn count_splits(&self) -> Option<u32> {
+        let width = self.content.width();
+        let mut splits: u32 = 0;
+        let mut beams = vec![false; width];
+        let mut next = vec![false; width];
+        beams[self.source.column] = true;
+
+        for row in self.source.row + 1..self.content.height() {
+            let elements = self.content.row(row);
+            next.fill(false);
+
+            for column in (0..width).filter(|column| beams[*column]) {
+                if elements[column] == Element::Splitter {
+                    splits = splits.checked_add(1)?;
+                    if let Some(left) = column.checked_sub(1) {
+                        next[left] = true;
+                    }
+                    if let Some(right) = next.get_mut(column + 1) {
+                        *right = true;
+                    }
+                } else {
+                    next[column] = true;
+                }
+            }
+
+            std::mem::swap(&mut beams, &mut next);
+        }
+
+        Some(splits)
+    }
+}
+
+/// Add `count` to the timelines already reaching a cell, `None` on overflow.
+fn merge<C: Counter>(counter: &C, cell: &mut Option<C::Value>, count: &C::Value) -> Option<()> {
+    let merged = match cell {
+        Some(existing) => counter.add(existing, count)?,
+        None => count.clone(),
+    };
+    *cell = Some(merged);
+    Some(())
+}
+
+pub struct Day7;
+
+/// Counter of the timelines of part 2.
+#[cfg(not(feature = "big-integer"))]
+type Timelines = U128;
+#[cfg(feature = "big-integer")]
+type Timelines = Big;
+
+impl Solution for Day7 {
+    const DAY: u8 = 7;
+
+    type Input<'a> = Manifold;
+    type Part1 = u32;
+    type Part2 = <Timelines as Counter>::Value;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        Manifold::from_str(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Result<Self::Part1, SolveError> {
+        input.count_splits().ok_or(SolveError::new(format!(
+            "The split count overflows {} bits",
+            u32::BITS
+        )))
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Result<Self::Part2, SolveError> {
+        input
+            .launch_beam_with(&Timelines::default())
+            .map_err(|overflow| SolveError::new(overflow.to_string()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    static TEST_DATA: &str = ".......S.......
+...............
+.......^.......
+...............
+......^.^......
+...............
+.....^.^.^.....
+...............
+....^.^...^....
+...............
+...^.^...^.^...
+...............
+..^...^.....^..
+...............
+.^.^.^.^.^...^.
+...............";
+
+    #[test]
+    fn test_parse_manifold() {
+        let manifold = Manifold::from_str(TEST_DATA).expect("Invalid manifold");
+
+        assert_eq!(manifold.source, Coordinate::new(0, 7));
+        assert_eq!(
+            (manifold.content.width(), manifold.content.height()),
+            (15, 16)
+        );
+        assert_eq!(
+            manifold
+                .content
+                .iter()
+                .filter(|(_, element)| **element == Element::Splitter)
+                .count(),
+            22
+        );
+        assert_eq!(manifold.to_string(), TEST_DATA);
+    }
+
+    #[test]
+    fn test_example_part_1() {
+        let manifold = Manifold::from_str(TEST_DATA).expect("Invalid manifold");
+
+        assert_eq!(manifold.count_splits(), Some(21))
+    }
+
+    #[test]
+    fn test_example_part_2() {
+        let manifold = Manifold::from_str(TEST_DATA).expect("Invalid manifold");
+
+        assert_eq!(manifold.launch_beam(), Ok(40))
+    }
+
+    #[test]
+    fn test_splitters_on_the_edges() {
+        let manifold = Manifold::from_str("S..\n^..\n.^.\n...").expect("Invalid manifold");
+
+        assert_eq!(manifold.count_splits(), Some(2));
+        assert_eq!(manifold.launch_beam(), Ok(2));
+    }
+
+    /// Rows full of splitters double the timelines each time.
+    fn cascade(levels: usize) -> Manifold {
+        let width = 2 * levels + 1;
+        let source = format!("{}S{}", ".".repeat(levels), ".".repeat(levels));
+        let splitters = "^".repeat(width);
+        let rows: Vec<&str> = std::iter::once(source.as_str())
+            .chain(std::iter::repeat_n(splitters.as_str(), levels))
+            .collect();
+        Manifold::from_str(&rows.join("\n")).expect("Invalid manifold")
+    }
+
+    #[test]
+    fn test_counters() {
+        let manifold = cascade(70);
+
+        assert!(manifold.launch_beam().is_err());
+        assert_eq!(manifold.launch_beam_with(&U128), Ok(1 << 70));
+
+        let modulus = 1_000_000_007;
+        let expected = (0..70).fold(1u64, |power, _| power * 2 % modulus);
+        assert_eq!(
+            manifold.launch_beam_with(&Modular::new(modulus)),
+            Ok(expected)
+        );
+        assert_eq!(manifold.launch_beam_with(&U64), Err(Overflow { line: 69 }));
+        assert_eq!(manifold.count_splits(), Some((1..=70).sum()));
+    }
+
+    #[cfg(feature = "big-integer")]
+    #[test]
+    fn test_big_counter() {
+        let manifold = cascade(200);
+
+        assert_eq!(
+            manifold.launch_beam_with(&Big),
+            Ok(num_bigint::BigUint::from(1u8) << 200)
+        );
+    }
+
+    #[test]
+    fn test_timelines_overflow() {
+        let input = cascade(130).to_string();
+        let parsed = Day7::parse(&input).expect("Invalid manifold");
+
+        assert_eq!(Day7::part1(&parsed), Ok((1..=130).sum()));
+        #[cfg(not(feature = "big-integer"))]
+        assert_eq!(
+            Day7::part2(&parsed).map_err(|e| e.reason),
+            Err("The timeline count overflows at line 131".to_string())
+        );
+        #[cfg(feature = "big-integer")]
+        assert_eq!(
+            Day7::part2(&parsed),
+            Ok(num_bigint::BigUint::from(1u8) << 130)
+        );
+    }
+
+    #[test]
+    fn test_parse_invalid_manifold() {
+        assert_eq!(
+            Manifold::from_str("..S..\n..^.#"),
+            Err(ParseError::unexpected(5, "#", "'S', '|', '^' or '.'").at_line(2))
+        );
+        assert_eq!(
+            Manifold::from_str("...\n.^."),
+            Err(ParseError::missing(1, "", "a source 'S'"))
+        );
+    }
+}
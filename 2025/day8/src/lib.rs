@@ -0,0 +1,224 @@
+mod pairs;
+mod spanning_tree;
+mod union_find;
+
+pub use pairs::ClosestPairs;
+pub use spanning_tree::{SpanningTree, kruskal, minimum_spanning_tree, prim};
+pub use union_find::{Merge, UnionFind};
+
+use aoc_runner::{ParseError, Solution, SolveError, numbered_lines, parse_number};
+use std::cmp::PartialEq;
+use std::fmt::{Display, Formatter};
+use std::ops::{Add, Sub};
+use std::str::FromStr;
+
+#[derive(Eq, PartialEq, Hash, Copy, Clone, Debug)]
+pub struct Position {
+    x: i64,
+    y: i64,
+    z: i64,
+}
+
+impl Display for Position {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "({},{},{})", self.x, self.y, self.z)
+    }
+}
+
+impl FromStr for Position {
+    type Err = ParseError;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let mut column = 1;
+        let coordinates = input
+            .split(",")
+            .map(|part| {
+                let number = parse_number::<i64>(part, column);
+                column += part.len() + 1;
+                number
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        match coordinates[..] {
+            [x, y, z] => Ok(Position { x, y, z }),
+            _ => Err(ParseError::unexpected(1, input, "three coordinates")),
+        }
+    }
+}
+
+impl Add for &Position {
+    type Output = Position;
+
+    fn add(self, other: Self) -> Position {
+        Position {
+            x: self.x + other.x,
+            y: self.y + other.y,
+            z: self.z + other.z,
+        }
+    }
+}
+
+impl Sub for &Position {
+    type Output = Position;
+
+    fn sub(self, other: Self) -> Self::Output {
+        Position {
+            x: self.x - other.x,
+            y: self.y - other.y,
+            z: self.z - other.z,
+        }
+    }
+}
+
+impl Position {
+    pub fn distance_squared(&self, other: &Position) -> i64 {
+        (self - other).len_squared()
+    }
+
+    fn len_squared(&self) -> i64 {
+        self.x.pow(2) + self.y.pow(2) + self.z.pow(2)
+    }
+}
+
+pub struct Day8;
+
+impl Solution for Day8 {
+    const DAY: u8 = 8;
+
+    type Input<'a> = Vec<Position>;
+    type Part1 = u64;
+    type Part2 = i64;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Result<Self::Part1, SolveError> {
+        largest_circuits(input, 1000)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Result<Self::Part2, SolveError> {
+        last_connection(input)
+    }
+}
+
+fn parse(input: &str) -> Result<Vec<Position>, ParseError> {
+    numbered_lines(input)
+        .map(|(line_number, line)| Position::from_str(line).map_err(|e| e.at_line(line_number)))
+        .collect()
+}
+
+/// Product of the sizes of the three largest circuits once the closest pairs are connected.
+fn largest_circuits(boxes: &[Position], shortest_limit: usize) -> Result<u64, SolveError> {
+    let pairs = ClosestPairs::with_limit(boxes, shortest_limit);
+    if pairs.len() < shortest_limit {
+        return Err(SolveError::new(format!(
+            "Only {} pairs of boxes, fewer than the {shortest_limit} to connect",
+            pairs.len()
+        )));
+    }
+
+    let mut circuits = UnionFind::new(boxes.len());
+    for (box1, box2, _) in pairs {
+        circuits.union(box1, box2);
+    }
+    if circuits.components() < 3 {
+        return Err(SolveError::new(format!(
+            "Fewer than three circuits left after {shortest_limit} connections"
+        )));
+    }
+
+    let mut sorted_circuits: Vec<u64> = circuits
+        .component_sizes()
+        .into_iter()
+        .map(|size| size as u64)
+        .collect();
+    sorted_circuits.sort();
+    sorted_circuits.reverse();
+
+    Ok(sorted_circuits.iter().take(3).product())
+}
+
+/// Product of the X coordinates of the pair of boxes making a single circuit.
+fn last_connection(boxes: &[Position]) -> Result<i64, SolveError> {
+    let mut circuits = UnionFind::new(boxes.len());
+
+    ClosestPairs::new(boxes)
+        .find(|(box1, box2, _)| {
+            circuits
+                .union(*box1, *box2)
+                .is_some_and(|merge| merge.components == 1)
+        })
+        .map(|(box1, box2, _)| boxes[box1].x * boxes[box2].x)
+        .ok_or(SolveError::new("The boxes never form a single circuit"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    static TEST_DATA: &str = "162,817,812
+57,618,57
+906,360,560
+592,479,940
+352,342,300
+466,668,158
+542,29,236
+431,825,988
+739,650,466
+52,470,668
+216,146,977
+819,987,18
+117,168,530
+805,96,715
+346,949,466
+970,615,88
+941,993,340
+862,61,35
+984,92,344
+425,690,689";
+
+    #[test]
+    fn test_example_part_1() {
+        let boxes = parse(TEST_DATA).expect("Invalid positions");
+
+        assert_eq!(largest_circuits(&boxes, 10), Ok(40))
+    }
+
+    #[test]
+    fn test_example_part_2() {
+        let boxes = parse(TEST_DATA).expect("Invalid positions");
+
+        assert_eq!(last_connection(&boxes), Ok(25272))
+    }
+
+    #[test]
+    fn test_not_enough_circuits() {
+        let boxes = parse(TEST_DATA).expect("Invalid positions");
+
+        assert_eq!(
+            largest_circuits(&boxes[..4], 10).map_err(|e| e.reason),
+            Err("Only 6 pairs of boxes, fewer than the 10 to connect".to_string())
+        );
+        assert_eq!(
+            largest_circuits(&boxes[..4], 5).map_err(|e| e.reason),
+            Err("Fewer than three circuits left after 5 connections".to_string())
+        );
+        assert_eq!(
+            last_connection(&boxes[..1]).map_err(|e| e.reason),
+            Err("The boxes never form a single circuit".to_string())
+        );
+    }
+
+    #[test]
+    fn test_parse_invalid_position() {
+        assert_eq!(
+            parse("162,817,812\n57,6x8,57"),
+            Err(ParseError::invalid_number(4, "6x8").at_line(2))
+        );
+        assert_eq!(
+            Position::from_str("1,2"),
+            Err(ParseError::unexpected(1, "1,2", "three coordinates"))
+        );
+    }
+}
@@ -0,0 +1,109 @@
+use crate::Position;
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+
+/// Pairs of junction boxes `(first, second, squared distance)` by ascending distance.
+///
+/// The distances are computed once and heapified in linear time, each pair is then
+/// ordered only when it is asked for, so taking the closest pairs never sorts them all.
+#[derive(Debug, Clone)]
+pub struct ClosestPairs {
+    heap: BinaryHeap<Reverse<(i64, usize, usize)>>,
+}
+
+impl ClosestPairs {
+    /// Stream every pair of boxes.
+    pub fn new(boxes: &[Position]) -> Self {
+        ClosestPairs {
+            heap: BinaryHeap::from(all_pairs(boxes)),
+        }
+    }
+
+    /// Stream only the `limit` closest pairs of boxes.
+    pub fn with_limit(boxes: &[Position], limit: usize) -> Self {
+        let mut pairs = all_pairs(boxes);
+        if limit < pairs.len() {
+            pairs.select_nth_unstable_by(limit, |a, b| b.cmp(a));
+            pairs.truncate(limit);
+        }
+
+        ClosestPairs {
+            heap: BinaryHeap::from(pairs),
+        }
+    }
+}
+
+impl Iterator for ClosestPairs {
+    type Item = (usize, usize, i64);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.heap
+            .pop()
+            .map(|Reverse((distance, first, second))| (first, second, distance))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.heap.len(), Some(self.heap.len()))
+    }
+}
+
+impl ExactSizeIterator for ClosestPairs {}
+
+fn all_pairs(boxes: &[Position]) -> Vec<Reverse<(i64, usize, usize)>> {
+    boxes
+        .iter()
+        .enumerate()
+        .flat_map(|(index, pos)| {
+            boxes
+                .iter()
+                .enumerate()
+                .skip(index + 1)
+                .map(move |(other_index, other)| {
+                    Reverse((pos.distance_squared(other), index, other_index))
+                })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn boxes() -> Vec<Position> {
+        [(0, 0, 0), (10, 0, 0), (1, 1, 1), (0, 5, 0), (3, 0, 4)]
+            .iter()
+            .map(|(x, y, z)| Position {
+                x: *x,
+                y: *y,
+                z: *z,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_pairs_are_ascending() {
+        let boxes = boxes();
+        let pairs: Vec<_> = ClosestPairs::new(&boxes).collect();
+
+        let mut expected: Vec<_> = all_pairs(&boxes)
+            .into_iter()
+            .map(|Reverse((distance, first, second))| (first, second, distance))
+            .collect();
+        expected.sort_by_key(|(first, second, distance)| (*distance, *first, *second));
+
+        assert_eq!(pairs.len(), 10);
+        assert_eq!(pairs, expected);
+        assert_eq!(pairs[0], (0, 2, 3));
+    }
+
+    #[test]
+    fn test_limited_pairs() {
+        let boxes = boxes();
+
+        let limited: Vec<_> = ClosestPairs::with_limit(&boxes, 3).collect();
+        let streamed: Vec<_> = ClosestPairs::new(&boxes).take(3).collect();
+
+        assert_eq!(limited, streamed);
+        assert_eq!(ClosestPairs::with_limit(&boxes, 50).count(), 10);
+    }
+}
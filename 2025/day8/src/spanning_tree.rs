@@ -0,0 +1,166 @@
+use crate::{ClosestPairs, Position, UnionFind};
+
+/// Edges of a minimum spanning tree weighted by squared distance, the longest last.
+#[derive(Debug, Eq, PartialEq, Clone)]
+pub struct SpanningTree {
+    pub edges: Vec<(Position, Position, i64)>,
+}
+
+impl SpanningTree {
+    /// Total length of the edges.
+    pub fn weight(&self) -> f64 {
+        self.edges
+            .iter()
+            .map(|(_, _, distance)| (*distance as f64).sqrt())
+            .sum()
+    }
+
+    /// Sum of the squared lengths of the edges, exact but not a length.
+    pub fn squared_weight(&self) -> i64 {
+        self.edges.iter().map(|(_, _, distance)| distance).sum()
+    }
+
+    /// The edge joining the last two circuits into one.
+    pub fn last_edge(&self) -> Option<&(Position, Position, i64)> {
+        self.edges.last()
+    }
+}
+
+/// Minimum spanning tree of the boxes, in O(n²) without building every pair.
+pub fn minimum_spanning_tree(boxes: &[Position]) -> SpanningTree {
+    prim(boxes)
+}
+
+pub fn kruskal(boxes: &[Position]) -> SpanningTree {
+    let mut circuits = UnionFind::new(boxes.len());
+    let mut edges = Vec::with_capacity(boxes.len().saturating_sub(1));
+
+    for (first, second, distance) in ClosestPairs::new(boxes) {
+        if let Some(merge) = circuits.union(first, second) {
+            edges.push((boxes[first], boxes[second], distance));
+
+            if merge.components == 1 {
+                break;
+            }
+        }
+    }
+
+    SpanningTree { edges }
+}
+
+pub fn prim(boxes: &[Position]) -> SpanningTree {
+    let mut in_tree = vec![false; boxes.len()];
+    let mut closest: Vec<(i64, usize)> = vec![(i64::MAX, 0); boxes.len()];
+    let mut edges = Vec::with_capacity(boxes.len().saturating_sub(1));
+
+    let mut current = 0;
+    for _ in 1..boxes.len() {
+        in_tree[current] = true;
+
+        let mut next: Option<usize> = None;
+        for (index, other) in boxes.iter().enumerate() {
+            if in_tree[index] {
+                continue;
+            }
+
+            let distance = boxes[current].distance_squared(other);
+            if distance < closest[index].0 {
+                closest[index] = (distance, current);
+            }
+
+            if next.is_none_or(|best| closest[index] < closest[best]) {
+                next = Some(index);
+            }
+        }
+
+        let Some(next) = next else {
+            break;
+        };
+        let (distance, parent) = closest[next];
+        edges.push((distance, parent.min(next), parent.max(next)));
+        current = next;
+    }
+
+    edges.sort();
+    SpanningTree {
+        edges: edges
+            .into_iter()
+            .map(|(distance, first, second)| (boxes[first], boxes[second], distance))
+            .collect(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse;
+
+    static TEST_DATA: &str = "162,817,812
+57,618,57
+906,360,560
+592,479,940
+352,342,300
+466,668,158
+542,29,236
+431,825,988
+739,650,466
+52,470,668
+216,146,977
+819,987,18
+117,168,530
+805,96,715
+346,949,466
+970,615,88
+941,993,340
+862,61,35
+984,92,344
+425,690,689";
+
+    #[test]
+    fn test_kruskal_and_prim_agree() {
+        let boxes = parse(TEST_DATA).expect("Invalid positions");
+
+        let kruskal = kruskal(&boxes);
+        let prim = prim(&boxes);
+
+        assert_eq!(kruskal.edges.len(), boxes.len() - 1);
+        assert_eq!(prim.edges.len(), boxes.len() - 1);
+        assert_eq!(kruskal.squared_weight(), prim.squared_weight());
+        assert!((kruskal.weight() - prim.weight()).abs() < 1e-6);
+        assert_eq!(kruskal.last_edge(), prim.last_edge());
+    }
+
+    #[test]
+    fn test_last_edge() {
+        let boxes = parse(TEST_DATA).expect("Invalid positions");
+
+        let (first, second, _) = *minimum_spanning_tree(&boxes)
+            .last_edge()
+            .expect("Empty tree");
+
+        assert_eq!(first.x * second.x, 25272);
+    }
+
+    #[test]
+    fn test_weight() {
+        let boxes = [
+            Position { x: 0, y: 0, z: 0 },
+            Position { x: 3, y: 4, z: 0 },
+            Position { x: 3, y: 4, z: 12 },
+        ];
+        let tree = minimum_spanning_tree(&boxes);
+
+        assert_eq!(tree.weight(), 17.0);
+        assert_eq!(tree.squared_weight(), 169);
+    }
+
+    #[test]
+    fn test_trivial_trees() {
+        assert_eq!(minimum_spanning_tree(&[]).last_edge(), None);
+        assert_eq!(kruskal(&[]).squared_weight(), 0);
+
+        let single = [Position { x: 1, y: 2, z: 3 }];
+        assert_eq!(prim(&single).edges, vec![]);
+        assert_eq!(kruskal(&single).edges, vec![]);
+    }
+}
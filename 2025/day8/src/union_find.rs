@@ -0,0 +1,123 @@
+/// Disjoint sets over `0..len` with path compression and union by size.
+#[derive(Debug, Clone)]
+pub struct UnionFind {
+    parent: Vec<usize>,
+    size: Vec<usize>,
+    components: usize,
+}
+
+/// Result of a union joining two different components.
+#[derive(Debug, Eq, PartialEq, Copy, Clone)]
+pub struct Merge {
+    pub root: usize,
+    pub size: usize,
+    pub components: usize,
+}
+
+impl UnionFind {
+    pub fn new(len: usize) -> Self {
+        UnionFind {
+            parent: (0..len).collect(),
+            size: vec![1; len],
+            components: len,
+        }
+    }
+
+    pub fn find(&mut self, element: usize) -> usize {
+        let mut root = element;
+        while self.parent[root] != root {
+            root = self.parent[root];
+        }
+
+        let mut current = element;
+        while self.parent[current] != root {
+            let next = self.parent[current];
+            self.parent[current] = root;
+            current = next;
+        }
+
+        root
+    }
+
+    /// Join the components of both elements, `None` when they were already connected.
+    pub fn union(&mut self, a: usize, b: usize) -> Option<Merge> {
+        let (mut root_a, mut root_b) = (self.find(a), self.find(b));
+        if root_a == root_b {
+            return None;
+        }
+
+        if self.size[root_a] < self.size[root_b] {
+            (root_a, root_b) = (root_b, root_a);
+        }
+
+        self.parent[root_b] = root_a;
+        self.size[root_a] += self.size[root_b];
+        self.components -= 1;
+
+        Some(Merge {
+            root: root_a,
+            size: self.size[root_a],
+            components: self.components,
+        })
+    }
+
+    pub fn connected(&mut self, a: usize, b: usize) -> bool {
+        self.find(a) == self.find(b)
+    }
+
+    pub fn size_of(&mut self, element: usize) -> usize {
+        let root = self.find(element);
+        self.size[root]
+    }
+
+    pub fn components(&self) -> usize {
+        self.components
+    }
+
+    pub fn component_sizes(&self) -> Vec<usize> {
+        self.parent
+            .iter()
+            .enumerate()
+            .filter(|(element, parent)| *element == **parent)
+            .map(|(root, _)| self.size[root])
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_union() {
+        let mut sets = UnionFind::new(5);
+
+        assert_eq!(sets.components(), 5);
+        assert_eq!(
+            sets.union(0, 1),
+            Some(Merge {
+                root: 0,
+                size: 2,
+                components: 4
+            })
+        );
+        assert_eq!(sets.union(1, 0), None);
+        assert_eq!(sets.union(2, 3).map(|m| m.components), Some(3));
+        assert_eq!(
+            sets.union(3, 1),
+            Some(Merge {
+                root: 2,
+                size: 4,
+                components: 2
+            })
+        );
+
+        assert!(sets.connected(2, 0));
+        assert!(!sets.connected(4, 0));
+        assert_eq!(sets.size_of(3), 4);
+
+        let mut sizes = sets.component_sizes();
+        sizes.sort();
+        assert_eq!(sizes, vec![1, 4]);
+    }
+}
@@ -1,17 +1,16 @@
+use aoc_runner::{ParseError, Solution, SolveError, numbered_lines, parse_number};
 use itertools::Itertools;
 use std::cmp::PartialEq;
-use std::fs::read_to_string;
 use std::str::FromStr;
-use std::time::Instant;
 
 #[derive(Eq, PartialEq, Hash, Copy, Clone, Debug)]
-struct Position {
+pub struct Position {
     x: i32,
     y: i32,
 }
 
 #[derive(Debug)]
-struct Rectangle {
+pub struct Rectangle {
     corners: (Position, Position),
     area: i64,
 }
@@ -25,27 +24,22 @@ struct Edge {
 }
 
 impl FromStr for Position {
-    type Err = String;
+    type Err = ParseError;
 
     fn from_str(input: &str) -> Result<Self, Self::Err> {
-        let coordinates: Vec<_> = input
+        let mut column = 1;
+        let coordinates = input
             .split(",")
-            .map(|part| part.parse::<i32>().map_err(|_| String::from("Invalid int")))
-            .collect();
-
-        if coordinates.len() != 2 {
-            Err(String::from("Invalid coordinates"))
-        } else {
-            Ok(Position {
-                x: coordinates
-                    .first()
-                    .ok_or(String::from("Missing coordinates"))?
-                    .clone()?,
-                y: coordinates
-                    .get(1)
-                    .ok_or(String::from("Missing coordinates"))?
-                    .clone()?,
+            .map(|part| {
+                let number = parse_number::<i32>(part, column);
+                column += part.len() + 1;
+                number
             })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        match coordinates[..] {
+            [x, y] => Ok(Position { x, y }),
+            _ => Err(ParseError::unexpected(1, input, "two coordinates")),
         }
     }
 }
@@ -85,31 +79,33 @@ impl Edge {
     }
 }
 
-fn main() {
-    let start_parsing = Instant::now();
-    let content = read_to_string("./day9/files/input").expect("Invalid file");
-    let corners = parse(&content);
-    let rectangles = create_rectangles(&corners);
-    let parsing_time = Instant::now() - start_parsing;
-    println!("Parsing took [{parsing_time:?}]");
+pub struct Day9;
+
+impl Solution for Day9 {
+    const DAY: u8 = 9;
 
-    let start_part1 = Instant::now();
-    let part1 = find_biggest(&rectangles);
-    let part1_time = Instant::now() - start_part1;
+    type Input<'a> = (Vec<Position>, Vec<Rectangle>);
+    type Part1 = i64;
+    type Part2 = i64;
 
-    let start_part2 = Instant::now();
-    let part2 = find_biggest_inside_limit(&corners, &rectangles);
-    let part2_time = Instant::now() - start_part2;
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        let corners = parse(input)?;
+        let rectangles = create_rectangles(&corners);
+        Ok((corners, rectangles))
+    }
 
-    println!("[{part1_time:?}] Sum for part 1 is {part1}");
-    println!("[{part2_time:?}] Sum for part 2 is {part2}");
+    fn part1((_, rectangles): &Self::Input<'_>) -> Result<Self::Part1, SolveError> {
+        Ok(find_biggest(rectangles))
+    }
+
+    fn part2((corners, rectangles): &Self::Input<'_>) -> Result<Self::Part2, SolveError> {
+        Ok(find_biggest_inside_limit(corners, rectangles))
+    }
 }
 
-fn parse(input: &str) -> Vec<Position> {
-    input
-        .lines()
-        .filter(|line| !line.is_empty())
-        .map(|line| Position::from_str(line).expect("Invalid coordinate"))
+fn parse(input: &str) -> Result<Vec<Position>, ParseError> {
+    numbered_lines(input)
+        .map(|(line_number, line)| Position::from_str(line).map_err(|e| e.at_line(line_number)))
         .collect()
 }
 
@@ -183,7 +179,7 @@ mod tests {
 
     #[test]
     fn test_example_part_1() {
-        let corners = parse(TEST_DATA);
+        let corners = parse(TEST_DATA).expect("Invalid positions");
         let biggest_area = create_rectangles(&corners)
             .iter()
             .max_by_key(|rect| rect.area)
@@ -195,7 +191,7 @@ mod tests {
 
     #[test]
     fn test_example_part_2() {
-        let corners = parse(TEST_DATA);
+        let corners = parse(TEST_DATA).expect("Invalid positions");
         let rectangles = create_rectangles(&corners);
 
         assert_eq!(find_biggest_inside_limit(&corners, &rectangles), 24);
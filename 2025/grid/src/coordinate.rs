@@ -0,0 +1,57 @@
+use std::fmt::{Display, Formatter};
+
+/// Offsets of the four cells sharing a side.
+pub const ORTHOGONAL: [(isize, isize); 4] = [(-1, 0), (0, -1), (0, 1), (1, 0)];
+
+/// Offsets of the eight cells sharing a side or a corner.
+pub const ADJACENT: [(isize, isize); 8] = [
+    (-1, -1),
+    (-1, 0),
+    (-1, 1),
+    (0, -1),
+    (0, 1),
+    (1, -1),
+    (1, 0),
+    (1, 1),
+];
+
+#[derive(Debug, Eq, PartialEq, Hash, Ord, PartialOrd, Copy, Clone)]
+pub struct Coordinate {
+    pub row: usize,
+    pub column: usize,
+}
+
+impl Coordinate {
+    pub const fn new(row: usize, column: usize) -> Self {
+        Coordinate { row, column }
+    }
+
+    /// Move by `(rows, columns)`, `None` when going before the first row or column.
+    pub fn offset(&self, (rows, columns): (isize, isize)) -> Option<Coordinate> {
+        Some(Coordinate {
+            row: self.row.checked_add_signed(rows)?,
+            column: self.column.checked_add_signed(columns)?,
+        })
+    }
+}
+
+impl Display for Coordinate {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "({}, {})", self.row, self.column)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_offset() {
+        let coordinate = Coordinate::new(1, 0);
+
+        assert_eq!(coordinate.offset((1, 2)), Some(Coordinate::new(2, 2)));
+        assert_eq!(coordinate.offset((-1, 0)), Some(Coordinate::new(0, 0)));
+        assert_eq!(coordinate.offset((0, -1)), None);
+        assert_eq!(coordinate.offset((-2, 0)), None);
+    }
+}
@@ -0,0 +1,280 @@
+mod coordinate;
+
+pub use coordinate::{ADJACENT, Coordinate, ORTHOGONAL};
+
+use aoc_runner::{ParseError, indentation, numbered_lines};
+use std::fmt::{Display, Formatter};
+use std::ops::{Index, IndexMut};
+
+/// A rectangle of cells stored row after row.
+#[derive(Debug, Eq, PartialEq, Hash, Clone)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    pub fn new(width: usize, height: usize, value: T) -> Self
+    where
+        T: Clone,
+    {
+        Grid {
+            width,
+            height,
+            cells: vec![value; width * height],
+        }
+    }
+
+    /// Build a grid from its cells given row after row.
+    pub fn from_cells(width: usize, cells: Vec<T>) -> Self {
+        assert!(
+            width > 0 && cells.len().is_multiple_of(width) || cells.is_empty(),
+            "The cells must fill whole rows"
+        );
+
+        Grid {
+            width,
+            height: cells.len().checked_div(width).unwrap_or(0),
+            cells,
+        }
+    }
+
+    /// Read a character map, `cell` returns `None` for a character that is not `expected`.
+    ///
+    /// Blank lines are skipped, the indentation of the lines is ignored and every row must
+    /// be as wide as the first one.
+    pub fn parse(
+        input: &str,
+        mut cell: impl FnMut(char) -> Option<T>,
+        expected: &'static str,
+    ) -> Result<Self, ParseError> {
+        let mut cells = Vec::new();
+        let mut width = None;
+
+        for (line_number, line) in numbered_lines(input) {
+            let offset = indentation(line);
+            let row = line.trim();
+            let start = cells.len();
+
+            for (index, c) in row.char_indices() {
+                if width.is_some_and(|width| cells.len() - start == width) {
+                    return Err(ParseError::unexpected(
+                        index + offset,
+                        &row[index..],
+                        "the end of the row",
+                    )
+                    .at_line(line_number));
+                }
+
+                cells.push(
+                    cell(c).ok_or(
+                        ParseError::unexpected(index + offset, &c.to_string(), expected)
+                            .at_line(line_number),
+                    )?,
+                );
+            }
+
+            let columns = cells.len() - start;
+            match width {
+                Some(width) if columns < width => {
+                    return Err(ParseError::missing(
+                        row.len() + offset,
+                        "",
+                        "a row as wide as the first one",
+                    )
+                    .at_line(line_number));
+                }
+                Some(_) => {}
+                None => width = Some(columns),
+            }
+        }
+
+        Ok(Grid::from_cells(width.unwrap_or(0), cells))
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, coordinate: Coordinate) -> bool {
+        coordinate.row < self.height && coordinate.column < self.width
+    }
+
+    pub fn get(&self, coordinate: Coordinate) -> Option<&T> {
+        self.contains(coordinate)
+            .then(|| &self.cells[coordinate.row * self.width + coordinate.column])
+    }
+
+    pub fn get_mut(&mut self, coordinate: Coordinate) -> Option<&mut T> {
+        self.contains(coordinate)
+            .then(|| &mut self.cells[coordinate.row * self.width + coordinate.column])
+    }
+
+    pub fn row(&self, row: usize) -> &[T] {
+        &self.cells[row * self.width..(row + 1) * self.width]
+    }
+
+    pub fn coordinates(&self) -> impl Iterator<Item = Coordinate> + use<T> {
+        let width = self.width;
+        (0..self.cells.len()).map(move |index| Coordinate::new(index / width, index % width))
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (Coordinate, &T)> {
+        self.coordinates().zip(&self.cells)
+    }
+
+    /// Coordinates at the given offsets that are inside the grid.
+    pub fn neighbours<'a>(
+        &self,
+        coordinate: Coordinate,
+        offsets: &'a [(isize, isize)],
+    ) -> impl Iterator<Item = Coordinate> + use<'a, T> {
+        let (width, height) = (self.width, self.height);
+        offsets
+            .iter()
+            .filter_map(move |offset| coordinate.offset(*offset))
+            .filter(move |neighbour| neighbour.row < height && neighbour.column < width)
+    }
+
+    /// Coordinates at the given offsets, leaving one side of the grid enters from the opposite one.
+    pub fn wrapping_neighbours<'a>(
+        &self,
+        coordinate: Coordinate,
+        offsets: &'a [(isize, isize)],
+    ) -> impl Iterator<Item = Coordinate> + use<'a, T> {
+        let (width, height) = (self.width as isize, self.height as isize);
+        offsets.iter().map(move |(rows, columns)| {
+            Coordinate::new(
+                (coordinate.row as isize + rows).rem_euclid(height) as usize,
+                (coordinate.column as isize + columns).rem_euclid(width) as usize,
+            )
+        })
+    }
+
+    pub fn neighbours4(&self, coordinate: Coordinate) -> impl Iterator<Item = Coordinate> + use<T> {
+        self.neighbours(coordinate, &ORTHOGONAL)
+    }
+
+    pub fn neighbours8(&self, coordinate: Coordinate) -> impl Iterator<Item = Coordinate> + use<T> {
+        self.neighbours(coordinate, &ADJACENT)
+    }
+
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+}
+
+impl<T> Index<Coordinate> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, coordinate: Coordinate) -> &Self::Output {
+        self.get(coordinate)
+            .unwrap_or_else(|| panic!("{coordinate} is outside of the grid"))
+    }
+}
+
+impl<T> IndexMut<Coordinate> for Grid<T> {
+    fn index_mut(&mut self, coordinate: Coordinate) -> &mut Self::Output {
+        self.get_mut(coordinate)
+            .unwrap_or_else(|| panic!("{coordinate} is outside of the grid"))
+    }
+}
+
+impl<T: Display> Display for Grid<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        for row in 0..self.height {
+            if row > 0 {
+                writeln!(f)?;
+            }
+            for cell in self.row(row) {
+                write!(f, "{cell}")?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn digits(input: &str) -> Result<Grid<u32>, ParseError> {
+        Grid::parse(input, |c| c.to_digit(10), "a digit")
+    }
+
+    #[test]
+    fn test_parse() {
+        let grid = digits("123\n\n  456").expect("Invalid grid");
+
+        assert_eq!(grid, Grid::from_cells(3, vec![1, 2, 3, 4, 5, 6]));
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid[Coordinate::new(1, 2)], 6);
+        assert_eq!(grid.get(Coordinate::new(2, 0)), None);
+        assert_eq!(grid.row(1), &[4, 5, 6]);
+        assert_eq!(digits(""), Ok(Grid::from_cells(0, vec![])));
+    }
+
+    #[test]
+    fn test_parse_invalid() {
+        assert_eq!(
+            digits("12\n1x"),
+            Err(ParseError::unexpected(2, "x", "a digit").at_line(2))
+        );
+        assert_eq!(
+            digits("12\n123"),
+            Err(ParseError::unexpected(3, "3", "the end of the row").at_line(2))
+        );
+        assert_eq!(
+            digits("12\n\n1"),
+            Err(ParseError::missing(2, "", "a row as wide as the first one").at_line(3))
+        );
+    }
+
+    #[test]
+    fn test_neighbours() {
+        let grid = Grid::new(3, 2, 0);
+
+        assert_eq!(
+            grid.neighbours4(Coordinate::new(0, 0)).collect::<Vec<_>>(),
+            vec![Coordinate::new(0, 1), Coordinate::new(1, 0)]
+        );
+        assert_eq!(grid.neighbours8(Coordinate::new(0, 1)).count(), 5);
+        assert_eq!(grid.neighbours8(Coordinate::new(1, 2)).count(), 3);
+        assert_eq!(
+            grid.neighbours(Coordinate::new(1, 1), &[(0, 5), (-1, 1)])
+                .count(),
+            1
+        );
+    }
+
+    #[test]
+    fn test_display() {
+        let grid = digits("123\n456").expect("Invalid grid");
+
+        assert_eq!(grid.to_string(), "123\n456");
+        assert_eq!(grid.map(|digit| digit % 2).to_string(), "101\n010");
+    }
+
+    #[test]
+    fn test_iter() {
+        let mut grid = Grid::new(2, 2, false);
+        grid[Coordinate::new(1, 0)] = true;
+
+        assert_eq!(
+            grid.iter()
+                .filter(|(_, cell)| **cell)
+                .map(|(coordinate, _)| coordinate)
+                .collect::<Vec<_>>(),
+            vec![Coordinate::new(1, 0)]
+        );
+    }
+}